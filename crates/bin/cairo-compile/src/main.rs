@@ -2,8 +2,12 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
-use cairo_lang_compiler::project::check_compiler_path;
-use cairo_lang_compiler::{CompilerConfig, compile_cairo_project_at_path};
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::{check_compiler_path, setup_project};
+use cairo_lang_compiler::{CompilerConfig, compile_prepared_db_program};
+use cairo_lang_filesystem::db::ExperimentalFeaturesConfig;
+use cairo_lang_filesystem::target::TargetConfig;
+use cairo_lang_utils::ice::install_ice_hook;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 
@@ -26,6 +30,34 @@ impl From<crate::InliningStrategy> for cairo_lang_lowering::utils::InliningStrat
     }
 }
 
+/// Options for the `opt-level` argument.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OptLevel {
+    /// No optional optimizations.
+    None,
+    /// The default optimizations.
+    #[default]
+    Default,
+}
+
+impl From<crate::OptLevel> for cairo_lang_filesystem::target::OptLevel {
+    fn from(value: crate::OptLevel) -> Self {
+        match value {
+            OptLevel::None => cairo_lang_filesystem::target::OptLevel::None,
+            OptLevel::Default => cairo_lang_filesystem::target::OptLevel::Default,
+        }
+    }
+}
+
+/// Options for the `experimental-features` argument.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExperimentalFeature {
+    /// Allows negative impls.
+    NegativeImpls,
+    /// Allows coupon types and coupon calls.
+    Coupons,
+}
+
 /// Compiles a Cairo project to Sierra.
 /// Exits with 0/1 if the compilation succeeds/fails.
 #[derive(Parser, Debug)]
@@ -44,6 +76,18 @@ struct Args {
     /// Overrides inlining behavior.
     #[arg(short, long, default_value = "default")]
     inlining_strategy: InliningStrategy,
+    /// The optimization level.
+    #[arg(long, default_value = "default")]
+    opt_level: OptLevel,
+    /// Experimental features to enable for all crates, in addition to those enabled per crate.
+    #[arg(long, value_delimiter = ',')]
+    experimental_features: Vec<ExperimentalFeature>,
+    /// A file to write a full report to, in case of an internal compiler error.
+    #[arg(long)]
+    ice_report: Option<PathBuf>,
+    /// Prints timing and query statistics of the compilation to stderr.
+    #[arg(long, default_value_t = false)]
+    stats: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -51,15 +95,37 @@ fn main() -> anyhow::Result<()> {
     log::info!("Starting Cairo compilation.");
 
     let args = Args::parse();
+    install_ice_hook(args.ice_report.clone());
 
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
 
-    let sierra_program = compile_cairo_project_at_path(&args.path, CompilerConfig {
+    let experimental_features = ExperimentalFeaturesConfig {
+        negative_impls: args.experimental_features.contains(&ExperimentalFeature::NegativeImpls),
+        coupons: args.experimental_features.contains(&ExperimentalFeature::Coupons),
+    };
+    let target_config = TargetConfig::default()
+        .with_experimental_features(experimental_features)
+        .with_opt_level(args.opt_level.into());
+
+    let mut db_builder = RootDatabase::builder();
+    db_builder
+        .with_inlining_strategy(args.inlining_strategy.into())
+        .with_target_config(target_config)
+        .detect_corelib();
+    if args.stats {
+        db_builder.with_stats();
+    }
+    let mut db = db_builder.build()?;
+    let main_crate_ids = setup_project(&mut db, &args.path)?;
+    let sierra_program = compile_prepared_db_program(&mut db, main_crate_ids, CompilerConfig {
         replace_ids: args.replace_ids,
         inlining_strategy: args.inlining_strategy.into(),
         ..CompilerConfig::default()
     })?;
+    if let Some(stats) = db.stats() {
+        eprint!("{stats}");
+    }
 
     match args.output {
         Some(path) => {
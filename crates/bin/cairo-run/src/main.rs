@@ -8,6 +8,7 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::{check_compiler_path, setup_project};
 use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_runner::casm_run::format_next_item;
 use cairo_lang_runner::profiling::ProfilingInfoProcessor;
 use cairo_lang_runner::{ProfilingInfoCollectionConfig, SierraCasmRunner, StarknetState};
@@ -16,6 +17,7 @@ use cairo_lang_sierra_generator::program_generator::SierraProgramWithDebug;
 use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
 use cairo_lang_starknet::contract::{find_contracts, get_contracts_info};
 use cairo_lang_utils::Upcast;
+use cairo_lang_utils::ice::install_ice_hook;
 use clap::Parser;
 
 /// Compiles a Cairo project and runs the function `main`.
@@ -40,10 +42,14 @@ struct Args {
     /// Whether to run the profiler.
     #[arg(long, default_value_t = false)]
     run_profiler: bool,
+    /// A file to write a full report to, in case of an internal compiler error.
+    #[arg(long)]
+    ice_report: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    install_ice_hook(args.ice_report.clone());
 
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
@@ -86,7 +92,8 @@ fn main() -> anyhow::Result<()> {
         contracts_info,
         if args.run_profiler { Some(ProfilingInfoCollectionConfig::default()) } else { None },
     )
-    .with_context(|| "Failed setting up runner.")?;
+    .with_context(|| "Failed setting up runner.")?
+    .with_gas_costs(db.target_config().gas_costs.clone());
     let result = runner
         .run_function_with_starknet_context(
             runner.find_function("::main")?,
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use anyhow::Ok;
 use cairo_lang_compiler::project::check_compiler_path;
 use cairo_lang_test_runner::{RunProfilerConfig, TestRunConfig, TestRunner};
+use cairo_lang_utils::ice::install_ice_hook;
 use clap::{Parser, ValueEnum};
 use serde::Serialize;
 
@@ -62,10 +63,14 @@ struct Args {
     /// Whether to print resource usage after each test.
     #[arg(long, default_value_t = false)]
     print_resource_usage: bool,
+    /// A file to write a full report to, in case of an internal compiler error.
+    #[arg(long)]
+    ice_report: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    install_ice_hook(args.ice_report.clone());
 
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
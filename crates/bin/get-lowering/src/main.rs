@@ -26,6 +26,7 @@ use cairo_lang_semantic::items::functions::{
     ImplGenericFunctionWithBodyId,
 };
 use cairo_lang_starknet::starknet_plugin_suite;
+use cairo_lang_utils::ice::install_ice_hook;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::{Intern, LookupIntern};
 use clap::Parser;
@@ -79,6 +80,9 @@ struct Args {
 
     /// The output file name (default: stdout).
     output: Option<String>,
+    /// A file to write a full report to, in case of an internal compiler error.
+    #[arg(long)]
+    ice_report: Option<PathBuf>,
 }
 
 /// Helper class for formatting the lowering phases of a concrete function.
@@ -219,6 +223,7 @@ fn get_func_id_by_name(
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    install_ice_hook(args.ice_report.clone());
 
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
@@ -1,9 +1,11 @@
 use std::fs;
+use std::path::PathBuf;
 
 use anyhow::Context;
 use cairo_lang_sierra::ProgramParser;
 use cairo_lang_sierra_to_casm::compiler::SierraToCasmConfig;
 use cairo_lang_sierra_to_casm::metadata::calc_metadata;
+use cairo_lang_utils::ice::install_ice_hook;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 use indoc::indoc;
@@ -16,6 +18,9 @@ struct Args {
     /// The path of the file to compile.
     file: String,
     output: String,
+    /// A file to write a full report to, in case of an internal compiler error.
+    #[arg(long)]
+    ice_report: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -23,6 +28,7 @@ fn main() -> anyhow::Result<()> {
     log::info!("Starting Sierra compilation.");
 
     let args = Args::parse();
+    install_ice_hook(args.ice_report.clone());
 
     let sierra_code = fs::read_to_string(args.file).with_context(|| "Could not read file!")?;
     let Ok(program) = ProgramParser::new().parse(&sierra_code) else {
@@ -7,6 +7,7 @@ use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::check_compiler_path;
 use cairo_lang_starknet::compile::starknet_compile;
 use cairo_lang_starknet_classes::allowed_libfuncs::ListSelector;
+use cairo_lang_utils::ice::install_ice_hook;
 use clap::Parser;
 
 /// Compiles the specified contract from a Cairo project, into a contract class file.
@@ -36,10 +37,14 @@ struct Args {
     /// A file of the allowed libfuncs list to use.
     #[arg(long)]
     allowed_libfuncs_list_file: Option<String>,
+    /// A file to write a full report to, in case of an internal compiler error.
+    #[arg(long)]
+    ice_report: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    install_ice_hook(args.ice_report.clone());
 
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
@@ -1,10 +1,12 @@
 use std::fs;
+use std::path::PathBuf;
 
 use anyhow::Context;
 use cairo_lang_starknet_classes::allowed_libfuncs::ListSelector;
 use cairo_lang_starknet_classes::casm_contract_class::CasmContractClass;
 use cairo_lang_starknet_classes::contract_class::{ContractClass, ContractEntryPoints};
 use cairo_lang_utils::bigint::BigUintAsHex;
+use cairo_lang_utils::ice::install_ice_hook;
 use clap::Parser;
 use serde::Deserialize;
 
@@ -29,6 +31,9 @@ struct Args {
     /// The max bytecode size.
     #[arg(long, default_value_t = 180000)]
     max_bytecode_size: usize,
+    /// A file to write a full report to, in case of an internal compiler error.
+    #[arg(long)]
+    ice_report: Option<PathBuf>,
 }
 
 /// Same as `ContractClass` - but ignores `abi` in deserialization.
@@ -44,6 +49,7 @@ pub struct ContractClassIgnoreAbi {
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    install_ice_hook(args.ice_report.clone());
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
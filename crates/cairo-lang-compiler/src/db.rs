@@ -11,6 +11,7 @@ use cairo_lang_filesystem::db::{
 use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{CrateId, FlagId, VirtualFile};
+use cairo_lang_filesystem::target::TargetConfig;
 use cairo_lang_lowering::db::{LoweringDatabase, LoweringGroup, init_lowering_group};
 use cairo_lang_parser::db::{ParserDatabase, ParserGroup};
 use cairo_lang_project::ProjectConfig;
@@ -24,6 +25,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::InliningStrategy;
 use crate::project::{update_crate_root, update_crate_roots_from_project_config};
+use crate::stats::CompilationStats;
 
 #[salsa::database(
     DefsDatabase,
@@ -36,8 +38,16 @@ use crate::project::{update_crate_root, update_crate_roots_from_project_config};
 )]
 pub struct RootDatabase {
     storage: salsa::Storage<RootDatabase>,
+    /// The statistics collected during compilation, if requested.
+    stats: Option<Arc<CompilationStats>>,
+}
+impl salsa::Database for RootDatabase {
+    fn salsa_event(&self, event: salsa::Event) {
+        if let Some(stats) = &self.stats {
+            stats.record_event(self, &event);
+        }
+    }
 }
-impl salsa::Database for RootDatabase {}
 impl ExternalFiles for RootDatabase {
     fn ext_as_virtual(&self, external_id: salsa::InternId) -> VirtualFile {
         ext_as_virtual_impl(self.upcast(), external_id)
@@ -45,7 +55,10 @@ impl ExternalFiles for RootDatabase {
 }
 impl salsa::ParallelDatabase for RootDatabase {
     fn snapshot(&self) -> salsa::Snapshot<RootDatabase> {
-        salsa::Snapshot::new(RootDatabase { storage: self.storage.snapshot() })
+        salsa::Snapshot::new(RootDatabase {
+            storage: self.storage.snapshot(),
+            stats: self.stats.clone(),
+        })
     }
 }
 impl RootDatabase {
@@ -54,8 +67,9 @@ impl RootDatabase {
         inline_macro_plugins: OrderedHashMap<String, Arc<dyn InlineMacroExprPlugin>>,
         analyzer_plugins: Vec<Arc<dyn AnalyzerPlugin>>,
         inlining_strategy: InliningStrategy,
+        stats: Option<Arc<CompilationStats>>,
     ) -> Self {
-        let mut res = Self { storage: Default::default() };
+        let mut res = Self { storage: Default::default(), stats };
         init_files_group(&mut res);
         init_lowering_group(&mut res, inlining_strategy);
         res.set_macro_plugins(plugins);
@@ -74,7 +88,20 @@ impl RootDatabase {
 
     /// Snapshots the db for read only.
     pub fn snapshot(&self) -> RootDatabase {
-        RootDatabase { storage: self.storage.snapshot() }
+        RootDatabase { storage: self.storage.snapshot(), stats: self.stats.clone() }
+    }
+
+    /// Returns the statistics collected during compilation, if requested on build.
+    pub fn stats(&self) -> Option<Arc<CompilationStats>> {
+        self.stats.clone()
+    }
+
+    /// Runs a compilation phase, recording its wall time if statistics are collected.
+    pub fn time_phase<T>(&self, phase: &'static str, f: impl FnOnce() -> T) -> T {
+        match &self.stats {
+            Some(stats) => stats.time_phase(phase, f),
+            None => f(),
+        }
     }
 }
 
@@ -91,7 +118,9 @@ pub struct RootDatabaseBuilder {
     auto_withdraw_gas: bool,
     project_config: Option<Box<ProjectConfig>>,
     cfg_set: Option<CfgSet>,
+    target_config: Option<TargetConfig>,
     inlining_strategy: InliningStrategy,
+    collect_stats: bool,
 }
 
 impl RootDatabaseBuilder {
@@ -102,7 +131,9 @@ impl RootDatabaseBuilder {
             auto_withdraw_gas: true,
             project_config: None,
             cfg_set: None,
+            target_config: None,
             inlining_strategy: InliningStrategy::Default,
+            collect_stats: false,
         }
     }
 
@@ -136,11 +167,22 @@ impl RootDatabaseBuilder {
         self
     }
 
+    pub fn with_target_config(&mut self, target_config: TargetConfig) -> &mut Self {
+        self.target_config = Some(target_config);
+        self
+    }
+
     pub fn skip_auto_withdraw_gas(&mut self) -> &mut Self {
         self.auto_withdraw_gas = false;
         self
     }
 
+    /// Collects timing and query statistics during compilation, see [RootDatabase::stats].
+    pub fn with_stats(&mut self) -> &mut Self {
+        self.collect_stats = true;
+        self
+    }
+
     pub fn build(&mut self) -> Result<RootDatabase> {
         // NOTE: Order of operations matters here!
         //   Errors if something is not OK are very subtle, mostly this results in missing
@@ -151,12 +193,17 @@ impl RootDatabaseBuilder {
             self.plugin_suite.inline_macro_plugins.clone(),
             self.plugin_suite.analyzer_plugins.clone(),
             self.inlining_strategy,
+            self.collect_stats.then(Default::default),
         );
 
         if let Some(cfg_set) = &self.cfg_set {
             db.use_cfg(cfg_set);
         }
 
+        if let Some(target_config) = &self.target_config {
+            db.set_target_config(Arc::new(target_config.clone()));
+        }
+
         if self.detect_corelib {
             let path =
                 detect_corelib().ok_or_else(|| anyhow!("Failed to find development corelib."))?;
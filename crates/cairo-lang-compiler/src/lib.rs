@@ -27,6 +27,7 @@ use crate::project::{ProjectConfig, get_main_crate_ids_from_project, setup_proje
 pub mod db;
 pub mod diagnostics;
 pub mod project;
+pub mod stats;
 
 #[cfg(test)]
 mod test;
@@ -134,17 +135,18 @@ pub fn compile_prepared_db(
     main_crate_ids: Vec<CrateId>,
     mut compiler_config: CompilerConfig<'_>,
 ) -> Result<SierraProgramWithDebug> {
-    compiler_config.diagnostics_reporter.ensure(db)?;
+    db.time_phase("diagnostics", || compiler_config.diagnostics_reporter.ensure(db))?;
 
     let mut sierra_program_with_debug = Arc::unwrap_or_clone(
-        db.get_sierra_program(main_crate_ids)
+        db.time_phase("sierra generation", || db.get_sierra_program(main_crate_ids))
             .to_option()
             .context("Compilation failed without any diagnostics")?,
     );
 
     if compiler_config.replace_ids {
-        sierra_program_with_debug.program =
-            replace_sierra_ids_in_program(db, &sierra_program_with_debug.program);
+        sierra_program_with_debug.program = db.time_phase("ids replacement", || {
+            replace_sierra_ids_in_program(db, &sierra_program_with_debug.program)
+        });
     }
 
     Ok(sierra_program_with_debug)
@@ -221,10 +223,12 @@ pub fn get_sierra_program_for_functions(
         spawn_warmup_db(db, requested_function_ids.clone());
     }
 
-    diagnostic_reporter.ensure(db)?;
-    db.get_sierra_program_for_functions(requested_function_ids)
-        .to_option()
-        .with_context(|| "Compilation failed without any diagnostics.")
+    db.time_phase("diagnostics", || diagnostic_reporter.ensure(db))?;
+    db.time_phase("sierra generation", || {
+        db.get_sierra_program_for_functions(requested_function_ids)
+    })
+    .to_option()
+    .with_context(|| "Compilation failed without any diagnostics.")
 }
 
 /// Runs Cairo compiler.
@@ -249,7 +253,7 @@ pub fn compile_prepared_db_program_artifact(
     let add_statements_functions = compiler_config.add_statements_functions;
     let add_statements_code_locations = compiler_config.add_statements_code_locations;
 
-    compiler_config.diagnostics_reporter.ensure(db)?;
+    db.time_phase("diagnostics", || compiler_config.diagnostics_reporter.ensure(db))?;
 
     let executable_functions = find_executable_function_ids(db, main_crate_ids.clone());
 
@@ -257,22 +261,27 @@ pub fn compile_prepared_db_program_artifact(
         // No executables found - compile for all main crates.
         // TODO(maciektr): Deprecate in future. This compilation is useless, without `replace_ids`.
         Arc::unwrap_or_clone(
-            db.get_sierra_program(main_crate_ids)
+            db.time_phase("sierra generation", || db.get_sierra_program(main_crate_ids))
                 .to_option()
                 .context("Compilation failed without any diagnostics")?,
         )
     } else {
         // Compile for executable functions only.
         Arc::unwrap_or_clone(
-            db.get_sierra_program_for_functions(executable_functions.clone().into_keys().collect())
-                .to_option()
-                .context("Compilation failed without any diagnostics")?,
+            db.time_phase("sierra generation", || {
+                db.get_sierra_program_for_functions(
+                    executable_functions.clone().into_keys().collect(),
+                )
+            })
+            .to_option()
+            .context("Compilation failed without any diagnostics")?,
         )
     };
 
     if compiler_config.replace_ids {
-        sierra_program_with_debug.program =
-            replace_sierra_ids_in_program(db, &sierra_program_with_debug.program);
+        sierra_program_with_debug.program = db.time_phase("ids replacement", || {
+            replace_sierra_ids_in_program(db, &sierra_program_with_debug.program)
+        });
     }
 
     let mut annotations = Annotations::default();
@@ -0,0 +1,146 @@
+//! Opt-in statistics of the compilation: wall time per compilation phase and per query, and query
+//! executions and revalidations.
+
+use std::cell::Cell;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use salsa::{DatabaseKeyIndex, Event, EventKind};
+
+use crate::db::RootDatabase;
+
+#[cfg(test)]
+#[path = "stats_test.rs"]
+mod test;
+
+/// The id of the next created [CompilationStats].
+static NEXT_STATS_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The last time query events were recorded in the current thread, along with the id of the
+    /// statistics they were recorded to, and the query running since.
+    static LAST_SAMPLE: Cell<Option<(usize, Instant, Option<QueryIndex>)>> =
+        const { Cell::new(None) };
+}
+
+/// Identifies a query of the database, regardless of its key: its query group index and its
+/// query index within the group.
+type QueryIndex = (u16, u16);
+
+/// Statistics of the executions of a single query, over all its keys.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryStats {
+    /// The wall time spent running the query, excluding nested queries. Sampled at query events,
+    /// and thus approximate.
+    pub time: Duration,
+    /// The number of memoized values reused after validating them against changed inputs. Values
+    /// reused in the revision they were computed or validated in are not counted, so in a single
+    /// compilation, with no input changes, this is always 0.
+    pub revalidations: usize,
+    /// The number of query executions.
+    pub executions: usize,
+}
+
+/// The collected statistics.
+#[derive(Debug, Default)]
+struct StatsData {
+    phases: OrderedHashMap<&'static str, Duration>,
+    /// The name and statistics of each query, in the order of their first event.
+    queries: OrderedHashMap<QueryIndex, (String, QueryStats)>,
+}
+impl StatsData {
+    /// Returns the statistics of the query of the given key, naming it on its first event.
+    fn query(&mut self, db: &RootDatabase, key: DatabaseKeyIndex) -> &mut QueryStats {
+        let (_, stats) =
+            self.queries.entry((key.group_index(), key.query_index())).or_insert_with(|| {
+                // Formatted as the query name followed by the key in parentheses.
+                let description = format!("{:?}", key.debug(db));
+                let name = description.split_once('(').map_or(&*description, |(name, _)| name);
+                (name.to_string(), QueryStats::default())
+            });
+        stats
+    }
+}
+
+/// Statistics collected during compilation. Shared between the database and its snapshots.
+#[derive(Debug)]
+pub struct CompilationStats {
+    /// A unique id of the statistics, for attributing query samples of the current thread.
+    id: usize,
+    data: Mutex<StatsData>,
+}
+impl Default for CompilationStats {
+    fn default() -> Self {
+        Self { id: NEXT_STATS_ID.fetch_add(1, Ordering::Relaxed), data: Default::default() }
+    }
+}
+impl CompilationStats {
+    /// Runs a compilation phase, adding its wall time to the phase's total.
+    pub fn time_phase<T>(&self, phase: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        *self.data.lock().unwrap().phases.entry(phase).or_default() += elapsed;
+        result
+    }
+
+    /// Returns the total wall time of each phase, in the order the phases first ran.
+    pub fn phases(&self) -> Vec<(&'static str, Duration)> {
+        self.data.lock().unwrap().phases.iter().map(|(phase, time)| (*phase, *time)).collect()
+    }
+
+    /// Returns the name and statistics of each query that ran, in the order they first ran.
+    pub fn queries(&self) -> Vec<(String, QueryStats)> {
+        self.data.lock().unwrap().queries.values().cloned().collect()
+    }
+
+    /// Records a salsa event of the given database.
+    pub(crate) fn record_event(&self, db: &RootDatabase, event: &Event) {
+        let now = Instant::now();
+        let runtime = salsa::Database::salsa_runtime(db);
+        let mut data = self.data.lock().unwrap();
+        if let Some((sample_id, since, Some(query_index))) = LAST_SAMPLE.get() {
+            if sample_id == self.id {
+                if let Some((_, query)) = data.queries.get_mut(&query_index) {
+                    query.time += now - since;
+                }
+            }
+        }
+        let running = match event.kind {
+            EventKind::WillExecute { database_key } => {
+                data.query(db, database_key).executions += 1;
+                Some(database_key)
+            }
+            EventKind::DidValidateMemoizedValue { database_key } => {
+                data.query(db, database_key).revalidations += 1;
+                runtime.active_query()
+            }
+            // The thread is idle until the other thread finishes the query.
+            EventKind::WillBlockOn { .. } => None,
+            EventKind::WillCheckCancellation => runtime.active_query(),
+        };
+        let running = running.map(|key| (key.group_index(), key.query_index()));
+        LAST_SAMPLE.set(Some((self.id, now, running)));
+    }
+}
+impl fmt::Display for CompilationStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Compilation phases:")?;
+        for (phase, time) in self.phases() {
+            writeln!(f, "  {phase}: {time:.2?}")?;
+        }
+        writeln!(f, "Queries, by time:")?;
+        let mut queries = self.queries();
+        queries.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.time));
+        for (query, QueryStats { time, revalidations, executions }) in queries {
+            writeln!(
+                f,
+                "  {query}: {time:.2?}, {executions} executions, {revalidations} revalidations"
+            )?;
+        }
+        Ok(())
+    }
+}
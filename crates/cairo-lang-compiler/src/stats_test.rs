@@ -0,0 +1,73 @@
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_semantic::test_utils::setup_test_crate;
+use indoc::indoc;
+
+use super::CompilationStats;
+use crate::db::RootDatabase;
+use crate::{CompilerConfig, compile_prepared_db_program};
+
+#[test]
+fn collects_stats() {
+    let content = indoc! {"
+        fn main() -> felt252 { x() + 1 }
+
+        fn x() -> felt252 { 12 }
+    "};
+    let mut db = RootDatabase::builder().detect_corelib().with_stats().build().unwrap();
+    let crate_id = setup_test_crate(&db, content);
+    let config = CompilerConfig { replace_ids: true, ..CompilerConfig::default() };
+    compile_prepared_db_program(&mut db, vec![crate_id], config).unwrap();
+    let stats = db.stats().unwrap();
+    assert_eq!(
+        stats.phases().into_iter().map(|(phase, _)| phase).collect::<Vec<_>>(),
+        vec!["diagnostics", "sierra generation", "ids replacement"]
+    );
+    let queries = stats.queries();
+    for name in ["priv_file_syntax_data", "priv_function_with_body_multi_lowering"] {
+        let (_, query_stats) = queries.iter().find(|(query, _)| query == name).unwrap();
+        assert!(query_stats.executions > 0, "No executions of `{name}`.");
+    }
+    for (query, query_stats) in queries {
+        assert!(
+            query.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+            "Bad name `{query}`."
+        );
+        assert_eq!(
+            query_stats.revalidations, 0,
+            "Revalidations of `{query}` with no input changes."
+        );
+    }
+}
+
+#[test]
+fn counts_revalidations_after_input_changes() {
+    let content = indoc! {"
+        fn main() -> felt252 { 12 }
+    "};
+    let mut db = RootDatabase::builder().detect_corelib().with_stats().build().unwrap();
+    let crate_id = setup_test_crate(&db, content);
+    let total_revalidations = |db: &RootDatabase| -> usize {
+        db.stats().unwrap().queries().into_iter().map(|(_, stats)| stats.revalidations).sum()
+    };
+    compile_prepared_db_program(&mut db, vec![crate_id], CompilerConfig::default()).unwrap();
+    assert_eq!(total_revalidations(&db), 0);
+    // Setting an input starts a new revision, even if the value is unchanged.
+    db.set_optimization_config(db.optimization_config());
+    compile_prepared_db_program(&mut db, vec![crate_id], CompilerConfig::default()).unwrap();
+    assert!(total_revalidations(&db) > 0);
+}
+
+#[test]
+fn distinguishes_stats_at_the_same_address() {
+    let first = Box::new(CompilationStats::default());
+    let first_id = first.id;
+    drop(first);
+    let second = Box::new(CompilationStats::default());
+    assert_ne!(second.id, first_id);
+}
+
+#[test]
+fn does_not_collect_stats_by_default() {
+    let db = RootDatabase::builder().detect_corelib().build().unwrap();
+    assert!(db.stats().is_none());
+}
@@ -1,13 +1,18 @@
+use std::sync::Arc;
+
 use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginResult};
+use cairo_lang_filesystem::db::{ExperimentalFeaturesConfig, FilesGroup};
+use cairo_lang_filesystem::target::{OptLevel, TargetConfig};
 use cairo_lang_semantic::plugin::PluginSuite;
-use cairo_lang_semantic::test_utils::setup_test_crate;
+use cairo_lang_semantic::test_utils::{setup_test_crate, setup_test_crate_ex};
 use cairo_lang_syntax::node::ast::ModuleItem;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use indoc::indoc;
 use smol_str::SmolStr;
 
 use crate::db::RootDatabase;
-use crate::{CompilerConfig, compile_prepared_db_program_artifact};
+use crate::diagnostics::get_diagnostics_as_string;
+use crate::{CompilerConfig, compile_prepared_db_program, compile_prepared_db_program_artifact};
 
 #[derive(Debug, Default)]
 pub struct MockExecutablePlugin {}
@@ -57,3 +62,93 @@ fn can_collect_executables() {
     assert_eq!(artefact.program.funcs.len(), 1);
     assert_eq!(artefact.program.funcs[0].id.debug_name, Some(SmolStr::new("test::test")));
 }
+
+#[test]
+fn target_experimental_features_apply_to_all_crates() {
+    let content = indoc! {"
+        fn foo() {}
+
+        fn bar(coupon: foo::Coupon) -> foo::Coupon {
+            coupon
+        }
+    "};
+    // The crate itself does not enable any experimental features.
+    let crate_settings = r#"edition = "2024_07""#;
+    let diagnostics = |target_config: TargetConfig| {
+        let db = RootDatabase::builder()
+            .detect_corelib()
+            .with_target_config(target_config)
+            .build()
+            .unwrap();
+        let crate_id = setup_test_crate_ex(&db, content, Some(crate_settings));
+        get_diagnostics_as_string(&db, &[crate_id])
+    };
+    assert!(diagnostics(TargetConfig::default()).contains("coupons experimental feature"));
+    let experimental_features = ExperimentalFeaturesConfig { negative_impls: false, coupons: true };
+    assert_eq!(
+        diagnostics(TargetConfig::default().with_experimental_features(experimental_features)),
+        ""
+    );
+}
+
+#[test]
+fn opt_level_controls_inlining() {
+    let content = indoc! {"
+        fn x() -> felt252 { 12 }
+
+        fn main() -> felt252 { x() }
+    "};
+    let compile = |opt_level: OptLevel| {
+        let mut db = RootDatabase::builder()
+            .detect_corelib()
+            .with_target_config(TargetConfig::default().with_opt_level(opt_level))
+            .build()
+            .unwrap();
+        let crate_id = setup_test_crate(&db, content);
+        let config = CompilerConfig { replace_ids: true, ..CompilerConfig::default() };
+        compile_prepared_db_program(&mut db, vec![crate_id], config).unwrap()
+    };
+    let calls_x =
+        |opt_level| compile(opt_level).to_string().contains("function_call<user@test::x>");
+    assert!(!calls_x(OptLevel::Default));
+    assert!(calls_x(OptLevel::None));
+}
+
+#[test]
+fn opt_level_set_after_build() {
+    let content = indoc! {"
+        fn x() -> felt252 { 12 }
+
+        fn main() -> felt252 { x() }
+    "};
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_test_crate(&db, content);
+    let calls_x = |db: &mut RootDatabase| {
+        let config = CompilerConfig { replace_ids: true, ..CompilerConfig::default() };
+        let program = compile_prepared_db_program(db, vec![crate_id], config).unwrap();
+        program.to_string().contains("function_call<user@test::x>")
+    };
+    assert!(!calls_x(&mut db));
+    db.set_target_config(Arc::new(TargetConfig::default().with_opt_level(OptLevel::None)));
+    assert!(calls_x(&mut db));
+}
+
+#[test]
+fn word_size_controls_string_words() {
+    let content = indoc! {r#"
+        fn main() -> ByteArray {
+            "abcdefghij"
+        }
+    "#};
+    let target_config = TargetConfig::default().with_word_size(64);
+    let mut db =
+        RootDatabase::builder().detect_corelib().with_target_config(target_config).build().unwrap();
+    let crate_id = setup_test_crate(&db, content);
+    let config = CompilerConfig { replace_ids: true, ..CompilerConfig::default() };
+    let program = compile_prepared_db_program(&mut db, vec![crate_id], config).unwrap();
+    let program = program.to_string();
+    // The first 7 bytes, "abcdefg", are a full 64-bit word, and the rest are the pending word.
+    assert!(program.contains(&format!("Const<bytes31, {}>", 0x61626364656667_u64)));
+    assert!(program.contains(&format!("Const<felt252, {}>", 0x68696a_u64)));
+    assert!(program.contains("Const<u32, 3>"));
+}
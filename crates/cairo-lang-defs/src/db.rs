@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::fmt::Write;
 use std::sync::Arc;
 
 use cairo_lang_diagnostics::{Maybe, ToMaybe};
@@ -21,6 +22,7 @@ use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::{Intern, LookupIntern, Upcast};
 use itertools::{Itertools, chain};
 use salsa::InternKey;
+use smol_str::SmolStr;
 
 use crate::ids::*;
 use crate::plugin::{
@@ -162,6 +164,10 @@ pub trait DefsGroup:
         free_function_id: FreeFunctionId,
     ) -> Maybe<Option<ast::FunctionWithBody>>;
     fn module_items(&self, module_id: ModuleId) -> Maybe<Arc<[ModuleItemId]>>;
+    /// Returns the items of a module, with their kinds and names. The items defined in the module's
+    /// file come first, in the order of their definition, followed by the items generated by
+    /// plugins.
+    fn module_item_defs(&self, module_id: ModuleId) -> Maybe<Arc<[ModuleItemDef]>>;
     /// Returns the stable ptr of the name of a module item.
     fn module_item_name_stable_ptr(
         &self,
@@ -1034,6 +1040,44 @@ fn module_items(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<Arc<[ModuleIte
     Ok(db.priv_module_data(module_id)?.items)
 }
 
+/// A direct child of a module, along with its kind and name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleItemDef {
+    pub id: ModuleItemId,
+    pub kind: ModuleItemKind,
+    pub name: SmolStr,
+}
+
+fn module_item_defs(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<Arc<[ModuleItemDef]>> {
+    Ok(db
+        .module_items(module_id)?
+        .iter()
+        .map(|id| ModuleItemDef { id: *id, kind: id.kind(), name: id.name(db) })
+        .collect())
+}
+
+/// Formats the items of a module, a line per item, including the items of its submodules, indented
+/// under them.
+pub fn format_module_items(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<String> {
+    fn format_inner(
+        db: &dyn DefsGroup,
+        module_id: ModuleId,
+        indent: usize,
+        output: &mut String,
+    ) -> Maybe<()> {
+        for ModuleItemDef { id, kind, name } in db.module_item_defs(module_id)?.iter() {
+            writeln!(output, "{:indent$}{kind} {name}", "").unwrap();
+            if let ModuleItemId::Submodule(submodule_id) = id {
+                format_inner(db, ModuleId::Submodule(*submodule_id), indent + 4, output)?;
+            }
+        }
+        Ok(())
+    }
+    let mut output = String::new();
+    format_inner(db, module_id, 0, &mut output)?;
+    Ok(output)
+}
+
 fn module_item_name_stable_ptr(
     db: &dyn DefsGroup,
     module_id: ModuleId,
@@ -339,6 +339,60 @@ define_language_element_id_as_enum! {
         ExternFunction(ExternFunctionId),
     }
 }
+impl ModuleItemId {
+    /// Returns the kind of the item.
+    pub fn kind(&self) -> ModuleItemKind {
+        match self {
+            ModuleItemId::Constant(_) => ModuleItemKind::Constant,
+            ModuleItemId::Submodule(_) => ModuleItemKind::Submodule,
+            ModuleItemId::Use(_) => ModuleItemKind::Use,
+            ModuleItemId::FreeFunction(_) => ModuleItemKind::FreeFunction,
+            ModuleItemId::Struct(_) => ModuleItemKind::Struct,
+            ModuleItemId::Enum(_) => ModuleItemKind::Enum,
+            ModuleItemId::TypeAlias(_) => ModuleItemKind::TypeAlias,
+            ModuleItemId::ImplAlias(_) => ModuleItemKind::ImplAlias,
+            ModuleItemId::Trait(_) => ModuleItemKind::Trait,
+            ModuleItemId::Impl(_) => ModuleItemKind::Impl,
+            ModuleItemId::ExternType(_) => ModuleItemKind::ExternType,
+            ModuleItemId::ExternFunction(_) => ModuleItemKind::ExternFunction,
+        }
+    }
+}
+
+/// The kind of a direct child of a module.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ModuleItemKind {
+    Constant,
+    Submodule,
+    Use,
+    FreeFunction,
+    Struct,
+    Enum,
+    TypeAlias,
+    ImplAlias,
+    Trait,
+    Impl,
+    ExternType,
+    ExternFunction,
+}
+impl std::fmt::Display for ModuleItemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleItemKind::Constant => write!(f, "const"),
+            ModuleItemKind::Submodule => write!(f, "mod"),
+            ModuleItemKind::Use => write!(f, "use"),
+            ModuleItemKind::FreeFunction => write!(f, "fn"),
+            ModuleItemKind::Struct => write!(f, "struct"),
+            ModuleItemKind::Enum => write!(f, "enum"),
+            ModuleItemKind::TypeAlias => write!(f, "type"),
+            ModuleItemKind::ImplAlias => write!(f, "impl alias"),
+            ModuleItemKind::Trait => write!(f, "trait"),
+            ModuleItemKind::Impl => write!(f, "impl"),
+            ModuleItemKind::ExternType => write!(f, "extern type"),
+            ModuleItemKind::ExternFunction => write!(f, "extern fn"),
+        }
+    }
+}
 define_top_level_language_element_id!(
     SubmoduleId,
     SubmoduleLongId,
@@ -17,7 +17,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::{Intern, LookupIntern, Upcast, extract_matches, try_extract_matches};
 use indoc::indoc;
 
-use crate::db::{DefsDatabase, DefsGroup, ext_as_virtual_impl};
+use crate::db::{DefsDatabase, DefsGroup, ext_as_virtual_impl, format_module_items};
 use crate::ids::{
     FileIndex, GenericParamLongId, ModuleFileId, ModuleId, ModuleItemId, NamedLanguageElementId,
     SubmoduleLongId,
@@ -77,11 +77,21 @@ cairo_lang_test_utils::test_file_test!(
     },
     test_generic_item_id
 );
+cairo_lang_test_utils::test_file_test!(
+    module_items,
+    "src/test_data",
+    {
+        module_items: "module_items",
+    },
+    test_module_items
+);
 fn test_generic_item_id(
     inputs: &OrderedHashMap<String, String>,
     _args: &OrderedHashMap<String, String>,
 ) -> TestRunnerResult {
     let mut db_val = DatabaseForTesting::default();
+    // Without plugins, so that only the items of the code are formatted.
+    db_val.set_macro_plugins(vec![]);
     let module_id = setup_test_module(&mut db_val, inputs["module_code"].as_str());
 
     let module_file_id = ModuleFileId(module_id, FileIndex(0));
@@ -125,6 +135,18 @@ fn test_generic_item_id(
     TestRunnerResult::success(OrderedHashMap::from([("output".into(), output)]))
 }
 
+fn test_module_items(
+    inputs: &OrderedHashMap<String, String>,
+    _args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let mut db_val = DatabaseForTesting::default();
+    // Without plugins, so that only the items of the code are formatted.
+    db_val.set_macro_plugins(vec![]);
+    let module_id = setup_test_module(&mut db_val, inputs["module_code"].as_str());
+    let output = format_module_items(&db_val, module_id).unwrap();
+    TestRunnerResult::success(OrderedHashMap::from([("output".into(), output)]))
+}
+
 pub fn setup_test_module<T: DefsGroup + AsFilesGroupMut + ?Sized>(
     db: &mut T,
     content: &str,
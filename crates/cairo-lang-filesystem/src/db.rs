@@ -16,6 +16,7 @@ use crate::ids::{
     VirtualFile,
 };
 use crate::span::{FileSummary, TextOffset, TextSpan, TextWidth};
+use crate::target::TargetConfig;
 
 #[cfg(test)]
 #[path = "db_test.rs"]
@@ -171,6 +172,9 @@ pub trait FilesGroup: ExternalFiles {
     /// The `#[cfg(...)]` options.
     #[salsa::input]
     fn cfg_set(&self) -> Arc<CfgSet>;
+    /// The settings of the compilation target.
+    #[salsa::input]
+    fn target_config(&self) -> Arc<TargetConfig>;
 
     /// List of crates in the project.
     fn crates(&self) -> Vec<CrateId>;
@@ -193,6 +197,7 @@ pub fn init_files_group(db: &mut (dyn FilesGroup + 'static)) {
     db.set_crate_configs(Arc::new(OrderedHashMap::default()));
     db.set_flags(Arc::new(OrderedHashMap::default()));
     db.set_cfg_set(Arc::new(CfgSet::new()));
+    db.set_target_config(Arc::new(TargetConfig::default()));
 }
 
 pub fn init_dev_corelib(db: &mut (dyn FilesGroup + 'static), core_lib_dir: PathBuf) {
@@ -1,13 +1,15 @@
 use std::sync::Arc;
 
+use cairo_lang_sierra::extensions::gas::CostTokenType;
 use cairo_lang_utils::Upcast;
 use test_log::test;
 
 use super::FilesGroup;
 use crate::cfg::{Cfg, CfgSet};
-use crate::db::{CrateConfiguration, FilesGroupEx};
+use crate::db::{CrateConfiguration, ExperimentalFeaturesConfig, FilesGroupEx};
 use crate::flag::Flag;
 use crate::ids::{CrateId, Directory, FlagId};
+use crate::target::{DEFAULT_WORD_SIZE, OptLevel, TargetConfig};
 use crate::test_utils::FilesDatabaseForTesting;
 
 #[test]
@@ -53,3 +55,27 @@ fn test_cfgs() {
         CfgSet::from_iter([Cfg::name("test"), Cfg::kv("k", "v1"), Cfg::kv("k", "v2")])
     )
 }
+
+#[test]
+fn test_target_config() {
+    let mut db = FilesDatabaseForTesting::default();
+
+    assert_eq!(*db.target_config(), TargetConfig::default());
+    assert_eq!(db.target_config().word_size, DEFAULT_WORD_SIZE);
+    assert_eq!(db.target_config().word_bytes(), 31);
+    assert_eq!(db.target_config().opt_level, OptLevel::Default);
+
+    let target_config = TargetConfig::default()
+        .with_word_size(64)
+        .with_gas_cost(CostTokenType::Pedersen, 2)
+        .with_experimental_features(ExperimentalFeaturesConfig {
+            negative_impls: true,
+            coupons: false,
+        })
+        .with_opt_level(OptLevel::None);
+    db.set_target_config(Arc::new(target_config.clone()));
+
+    assert_eq!(*db.target_config(), target_config);
+    assert_eq!(db.target_config().word_bytes(), 7);
+    assert_eq!(db.target_config().gas_costs[&CostTokenType::Pedersen], 2);
+}
@@ -6,4 +6,5 @@ pub mod detect;
 pub mod flag;
 pub mod ids;
 pub mod span;
+pub mod target;
 pub mod test_utils;
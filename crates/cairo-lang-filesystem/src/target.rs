@@ -0,0 +1,77 @@
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use crate::db::ExperimentalFeaturesConfig;
+
+/// The default size of a machine word, in bits - the size of a `felt252`.
+pub const DEFAULT_WORD_SIZE: usize = 252;
+
+/// Settings of the target the code is compiled for, shared by all crates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TargetConfig {
+    /// The size of a machine word of the target, in bits. The corelib must match it, e.g. in the
+    /// size of `bytes31`.
+    pub word_size: usize,
+    /// Overrides for the gas costs of the builtin cost tokens, used when running the code. Tokens
+    /// without an override cost their default value.
+    pub gas_costs: OrderedHashMap<CostTokenType, usize>,
+    /// Experimental features enabled for all crates, in addition to those enabled per crate.
+    pub experimental_features: ExperimentalFeaturesConfig,
+    /// The optimization level to compile with.
+    pub opt_level: OptLevel,
+}
+impl TargetConfig {
+    /// Sets the size of a machine word, in bits.
+    pub fn with_word_size(mut self, word_size: usize) -> Self {
+        self.word_size = word_size;
+        self
+    }
+    /// Overrides the gas cost of a builtin cost token.
+    pub fn with_gas_cost(mut self, token: CostTokenType, cost: usize) -> Self {
+        assert!(
+            CostTokenType::iter_precost().any(|precost_token| *precost_token == token),
+            "Only builtin cost tokens have configurable gas costs, got {token:?}."
+        );
+        self.gas_costs.insert(token, cost);
+        self
+    }
+    /// Sets the experimental features enabled for all crates.
+    pub fn with_experimental_features(
+        mut self,
+        experimental_features: ExperimentalFeaturesConfig,
+    ) -> Self {
+        self.experimental_features = experimental_features;
+        self
+    }
+    /// Sets the optimization level.
+    pub fn with_opt_level(mut self, opt_level: OptLevel) -> Self {
+        self.opt_level = opt_level;
+        self
+    }
+    /// Returns the number of whole bytes that always fit in a machine word, e.g. 31 for a
+    /// `felt252`.
+    pub fn word_bytes(&self) -> usize {
+        (self.word_size - 1) / 8
+    }
+}
+impl Default for TargetConfig {
+    fn default() -> Self {
+        Self {
+            word_size: DEFAULT_WORD_SIZE,
+            gas_costs: Default::default(),
+            experimental_features: Default::default(),
+            opt_level: Default::default(),
+        }
+    }
+}
+
+/// The level of optimizations applied during compilation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OptLevel {
+    /// No optional optimizations: only functions annotated with `#[inline(always)]` are inlined,
+    /// and constants are not folded.
+    None,
+    /// The default optimizations.
+    #[default]
+    Default,
+}
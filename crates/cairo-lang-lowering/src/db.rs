@@ -1,12 +1,16 @@
 use std::sync::Arc;
 
 use cairo_lang_defs as defs;
-use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId, NamedLanguageElementLongId};
+use cairo_lang_defs::ids::{
+    LanguageElementId, ModuleId, ModuleItemId, NamedLanguageElementLongId,
+    TopLevelLanguageElementId,
+};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
 use cairo_lang_semantic::{self as semantic, ConcreteTypeId, TypeId, TypeLongId, corelib};
+use cairo_lang_utils::ice::with_context;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
@@ -334,6 +338,11 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
     #[salsa::input]
     fn optimization_config(&self) -> Arc<OptimizationConfig>;
 
+    /// Internal query for the configuration of the optimization passes, with the optimizations
+    /// excluded by the optimization level of the target disabled.
+    #[salsa::invoke(crate::optimizations::config::priv_optimization_config)]
+    fn priv_optimization_config(&self) -> Arc<OptimizationConfig>;
+
     /// Returns the final optimization strategy that is applied on top of
     /// inlined_function_optimization_strategy.
     #[salsa::invoke(crate::optimizations::strategy::final_optimization_strategy)]
@@ -381,8 +390,10 @@ fn priv_function_with_body_multi_lowering(
     db: &dyn LoweringGroup,
     function_id: defs::ids::FunctionWithBodyId,
 ) -> Maybe<Arc<MultiLowering>> {
-    let multi_lowering = lower_semantic_function(db.upcast(), function_id)?;
-    Ok(Arc::new(multi_lowering))
+    with_context(format!("lowering function `{}`", function_id.full_path(db.upcast())), || {
+        let multi_lowering = lower_semantic_function(db.upcast(), function_id)?;
+        Ok(Arc::new(multi_lowering))
+    })
 }
 
 // * Borrow checking.
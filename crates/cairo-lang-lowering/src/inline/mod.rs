@@ -68,7 +68,7 @@ pub fn priv_should_inline(
         function_id.function_with_body_id(db).base_semantic_function(db),
     )?;
 
-    Ok(match db.optimization_config().inlining_strategy {
+    Ok(match db.priv_optimization_config().inlining_strategy {
         InliningStrategy::Default => match config {
             InlineConfiguration::Never(_) => false,
             InlineConfiguration::Should(_) => true,
@@ -373,5 +373,5 @@ pub fn apply_inlining(
 /// Returns the threshold, in number of lowering statements, below which a function is marked as
 /// `should_inline`.
 fn inline_small_functions_threshold(db: &dyn LoweringGroup) -> usize {
-    db.optimization_config().inline_small_functions_threshold
+    db.priv_optimization_config().inline_small_functions_threshold
 }
@@ -992,7 +992,8 @@ fn build_empty_data_array(
     .returns[0]
 }
 
-/// Emits lowering statements to add 31-byte words to the given data array.
+/// Emits lowering statements to add the full words of a string literal, e.g. 31 bytes each for a
+/// `felt252` word size, to the given data array.
 fn add_chunks_to_data_array<'a>(
     ctx: &mut LoweringContext<'_, '_>,
     builder: &mut BlockBuilder,
@@ -1004,7 +1005,7 @@ fn add_chunks_to_data_array<'a>(
 ) -> &'a [u8] {
     let expr_stable_ptr = expr.stable_ptr.untyped();
 
-    let chunks = expr.value.as_bytes().chunks_exact(31);
+    let chunks = expr.value.as_bytes().chunks_exact(ctx.db.target_config().word_bytes());
     let remainder = chunks.remainder();
     for chunk in chunks {
         let chunk_usage = generators::Const {
@@ -1048,7 +1049,7 @@ fn add_pending_word(
     }
     .add(ctx, &mut builder.statements);
 
-    let pending_word_len = expr.value.len() % 31;
+    let pending_word_len = pending_word_bytes.len();
     let pending_word_len_usage = generators::Const {
         value: ConstValue::Int(pending_word_len.into(), u32_ty),
         ty: u32_ty,
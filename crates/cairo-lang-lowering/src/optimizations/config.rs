@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use cairo_lang_filesystem::target::OptLevel;
 use cairo_lang_semantic::corelib;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_utils::Intern;
@@ -56,6 +57,15 @@ impl OptimizationConfig {
         self.skip_const_folding = skip_const_folding;
         self
     }
+    /// Disables the optimizations excluded by the given optimization level.
+    pub fn with_opt_level(self, opt_level: OptLevel) -> Self {
+        match opt_level {
+            OptLevel::None => {
+                self.with_inlining_strategy(InliningStrategy::Avoid).with_skip_const_folding(true)
+            }
+            OptLevel::Default => self,
+        }
+    }
 }
 
 impl Default for OptimizationConfig {
@@ -97,5 +107,11 @@ pub fn priv_movable_function_ids(db: &dyn LoweringGroup) -> Arc<UnorderedHashSet
         panic!("Got empty string as movable_function");
     };
 
-    Arc::new(db.optimization_config().moveable_functions.iter().map(libfunc_by_name).collect())
+    Arc::new(db.priv_optimization_config().moveable_functions.iter().map(libfunc_by_name).collect())
+}
+
+/// Query implementation of [LoweringGroup::priv_optimization_config].
+pub fn priv_optimization_config(db: &dyn LoweringGroup) -> Arc<OptimizationConfig> {
+    let opt_level = db.target_config().opt_level;
+    Arc::new(db.optimization_config().as_ref().clone().with_opt_level(opt_level))
 }
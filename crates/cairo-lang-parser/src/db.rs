@@ -5,6 +5,7 @@ use cairo_lang_syntax::node::ast::{Expr, SyntaxFile};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
 use cairo_lang_utils::Upcast;
+use cairo_lang_utils::ice::with_context;
 
 use crate::diagnostic::ParserDiagnostic;
 use crate::parser::Parser;
@@ -36,16 +37,18 @@ pub struct SyntaxData {
 }
 
 pub fn priv_file_syntax_data(db: &dyn ParserGroup, file_id: FileId) -> SyntaxData {
-    let mut diagnostics = DiagnosticsBuilder::default();
-    let syntax = db.file_content(file_id).to_maybe().map(|s| match file_id.kind(db.upcast()) {
-        FileKind::Module => {
-            Parser::parse_file(db.upcast(), &mut diagnostics, file_id, &s).as_syntax_node()
-        }
-        FileKind::Expr => {
-            Parser::parse_file_expr(db.upcast(), &mut diagnostics, file_id, &s).as_syntax_node()
-        }
-    });
-    SyntaxData { diagnostics: diagnostics.build(), syntax }
+    with_context(format!("parsing file `{}`", file_id.full_path(db.upcast())), || {
+        let mut diagnostics = DiagnosticsBuilder::default();
+        let syntax = db.file_content(file_id).to_maybe().map(|s| match file_id.kind(db.upcast()) {
+            FileKind::Module => {
+                Parser::parse_file(db.upcast(), &mut diagnostics, file_id, &s).as_syntax_node()
+            }
+            FileKind::Expr => {
+                Parser::parse_file_expr(db.upcast(), &mut diagnostics, file_id, &s).as_syntax_node()
+            }
+        });
+        SyntaxData { diagnostics: diagnostics.build(), syntax }
+    })
 }
 
 pub fn file_syntax(db: &dyn ParserGroup, file_id: FileId) -> Maybe<SyntaxNode> {
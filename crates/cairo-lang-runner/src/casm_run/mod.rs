@@ -2192,14 +2192,15 @@ pub struct RunFunctionContext<'a> {
 
 /// Runs CairoRunner on layout with prime.
 /// Allows injecting custom CairoRunner.
-pub fn run_function_with_runner(
+pub fn run_function_with_runner<F>(
     data_len: usize,
-    additional_initialization: fn(
-        context: RunFunctionContext<'_>,
-    ) -> Result<(), Box<CairoRunError>>,
+    additional_initialization: F,
     hint_processor: &mut dyn HintProcessor,
     runner: &mut CairoRunner,
-) -> Result<(), Box<CairoRunError>> {
+) -> Result<(), Box<CairoRunError>>
+where
+    F: FnOnce(RunFunctionContext<'_>) -> Result<(), Box<CairoRunError>>,
+{
     let end = runner.initialize(true).map_err(CairoRunError::from)?;
 
     additional_initialization(RunFunctionContext { vm: &mut runner.vm, data_len })?;
@@ -2246,15 +2247,16 @@ pub struct RunFunctionResult {
 
 /// Runs `bytecode` on layout with prime, and returns the matching [RunFunctionResult].
 /// Allows injecting custom HintProcessor.
-pub fn run_function<'a, 'b: 'a>(
+pub fn run_function<'a, 'b: 'a, F>(
     bytecode: impl Iterator<Item = &'a BigInt> + Clone,
     builtins: Vec<BuiltinName>,
-    additional_initialization: fn(
-        context: RunFunctionContext<'_>,
-    ) -> Result<(), Box<CairoRunError>>,
+    additional_initialization: F,
     hint_processor: &mut dyn HintProcessor,
     hints_dict: HashMap<usize, Vec<HintParams>>,
-) -> Result<RunFunctionResult, Box<CairoRunError>> {
+) -> Result<RunFunctionResult, Box<CairoRunError>>
+where
+    F: FnOnce(RunFunctionContext<'_>) -> Result<(), Box<CairoRunError>>,
+{
     let data: Vec<MaybeRelocatable> =
         bytecode.map(Felt252::from).map(MaybeRelocatable::from).collect();
     let data_len = data.len();
@@ -203,6 +203,8 @@ pub struct SierraCasmRunner {
     starknet_contracts_info: OrderedHashMap<Felt252, ContractInfo>,
     /// Whether to run the profiler when running using this runner.
     run_profiler: Option<ProfilingInfoCollectionConfig>,
+    /// Overrides for the gas costs of the builtin cost tokens.
+    gas_costs: OrderedHashMap<CostTokenType, usize>,
 }
 impl SierraCasmRunner {
     pub fn new(
@@ -231,9 +233,22 @@ impl SierraCasmRunner {
             casm_program,
             starknet_contracts_info,
             run_profiler,
+            gas_costs: Default::default(),
         })
     }
 
+    /// Overrides the gas costs of builtin cost tokens, e.g. by the gas costs of the target the
+    /// program was compiled for. Tokens without an override cost their default value.
+    pub fn with_gas_costs(mut self, gas_costs: OrderedHashMap<CostTokenType, usize>) -> Self {
+        self.gas_costs = gas_costs;
+        self
+    }
+
+    /// Returns the gas cost of a cost token, considering the overrides of the runner.
+    fn token_gas_cost(&self, token_type: CostTokenType) -> usize {
+        self.gas_costs.get(&token_type).copied().unwrap_or_else(|| token_gas_cost(token_type))
+    }
+
     /// Runs the vm starting from a function in the context of a given starknet state.
     pub fn run_function_with_starknet_context(
         &self,
@@ -452,8 +467,17 @@ impl SierraCasmRunner {
     {
         let return_types = self.generic_id_and_size_from_concrete(&func.signature.ret_types);
 
+        let builtin_costs = CostTokenType::iter_precost()
+            .map(|token_type| (*token_type, self.token_gas_cost(*token_type)))
+            .collect::<Vec<_>>();
         let RunFunctionResult { ap, used_resources, memory, relocated_trace } =
-            casm_run::run_function(bytecode, builtins, initialize_vm, hint_processor, hints_dict)?;
+            casm_run::run_function(
+                bytecode,
+                builtins,
+                |context| initialize_vm(context, &builtin_costs),
+                hint_processor,
+                hints_dict,
+            )?;
 
         let (results_data, gas_counter) = Self::get_results_data(&return_types, &memory, ap);
         assert!(results_data.len() <= 1);
@@ -745,7 +769,9 @@ impl SierraCasmRunner {
         Some(
             self.metadata.gas_info.function_costs[&func.id]
                 .iter()
-                .map(|(token_type, val)| val.into_or_panic::<usize>() * token_gas_cost(*token_type))
+                .map(|(token_type, val)| {
+                    val.into_or_panic::<usize>() * self.token_gas_cost(*token_type)
+                })
                 .sum(),
         )
     }
@@ -799,16 +825,19 @@ impl Default for ProfilingInfoCollectionConfig {
     }
 }
 
-/// Initializes a vm by adding a new segment with builtins cost and a necessary pointer at the end
-/// of the program
-pub fn initialize_vm(context: RunFunctionContext<'_>) -> Result<(), Box<CairoRunError>> {
+/// Initializes a vm by adding a new segment with the given builtins cost and a necessary pointer at
+/// the end of the program
+pub fn initialize_vm(
+    context: RunFunctionContext<'_>,
+    builtin_costs: &[(CostTokenType, usize)],
+) -> Result<(), Box<CairoRunError>> {
     let vm = context.vm;
-    // Create the builtin cost segment, with dummy values.
+    // Create the builtin cost segment.
     let builtin_cost_segment = vm.add_memory_segment();
-    for token_type in CostTokenType::iter_precost() {
+    for (token_type, cost) in builtin_costs {
         vm.insert_value(
             (builtin_cost_segment + (token_type.offset_in_builtin_costs() as usize)).unwrap(),
-            Felt252::from(token_gas_cost(*token_type)),
+            Felt252::from(*cost),
         )
         .map_err(|e| Box::new(e.into()))?;
     }
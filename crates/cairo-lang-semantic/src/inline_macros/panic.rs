@@ -10,8 +10,6 @@ use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
 use indoc::formatdoc;
 use num_bigint::BigUint;
 
-use super::write::FELT252_BYTES;
-
 /// Try to generate a simple panic handlic code.
 /// Return true if successful and updates the buiilder if successful.
 fn try_handle_simple_panic(
@@ -46,16 +44,17 @@ fn try_handle_simple_panic(
         return false;
     }
 
+    let word_bytes = db.target_config().word_bytes();
     builder.add_str(&format!(
         "core::panics::panic(array![core::byte_array::BYTE_ARRAY_MAGIC, {}, ",
-        format_str.len() / FELT252_BYTES,
+        format_str.len() / word_bytes,
     ));
 
-    for chunk in format_str.as_bytes().chunks(FELT252_BYTES) {
+    for chunk in format_str.as_bytes().chunks(word_bytes) {
         builder.add_str(&format!("{:#x}, ", BigUint::from_bytes_be(chunk)));
     }
 
-    let remainder_size = format_str.len() % FELT252_BYTES;
+    let remainder_size = format_str.len() % word_bytes;
     if remainder_size == 0 {
         // Adding the empty remainder word.
         builder.add_str("0, ");
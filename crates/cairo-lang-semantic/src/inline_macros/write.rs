@@ -13,8 +13,6 @@ use cairo_lang_utils::{OptionHelper, try_extract_matches};
 use indoc::indoc;
 use num_bigint::{BigInt, Sign};
 
-pub const FELT252_BYTES: usize = 31;
-
 /// Macro for writing into a formatter.
 #[derive(Debug, Default)]
 pub struct WriteMacro;
@@ -147,6 +145,8 @@ struct FormattingInfo {
     format_string: String,
     /// The positional arguments for the format string.
     args: Vec<ast::Expr>,
+    /// The number of bytes of the format string written to the formatter as a single word.
+    word_bytes: usize,
 }
 impl FormattingInfo {
     /// Extracts the arguments from a formatted string macro.
@@ -217,6 +217,7 @@ impl FormattingInfo {
             // `unwrap` is ok because the above `on_none` ensures it's not None.
             format_string,
             args,
+            word_bytes: db.target_config().word_bytes(),
         })
     }
 
@@ -414,7 +415,7 @@ impl FormattingInfo {
         pending_chars: &mut String,
         ident_count: usize,
     ) {
-        for chunk in pending_chars.as_bytes().chunks(FELT252_BYTES) {
+        for chunk in pending_chars.as_bytes().chunks(self.word_bytes) {
             self.add_indentation(builder, ident_count);
             builder.add_modified(RewriteNode::interpolate_patched(
                 &format!(
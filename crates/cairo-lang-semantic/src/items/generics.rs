@@ -427,6 +427,9 @@ pub fn semantic_generic_params_ex(
 
 /// Returns true if negative impls are enabled in the module.
 fn are_negative_impls_enabled(db: &dyn SemanticGroup, module_file_id: ModuleFileId) -> bool {
+    if db.target_config().experimental_features.negative_impls {
+        return true;
+    }
     let owning_crate = module_file_id.0.owning_crate(db.upcast());
     let Some(config) = db.crate_config(owning_crate) else { return false };
     config.settings.experimental_features.negative_impls
@@ -925,6 +925,9 @@ pub fn wrap_in_snapshots(db: &dyn SemanticGroup, mut ty: TypeId, n_snapshots: us
 
 /// Returns `true` if coupons are enabled in the module.
 pub(crate) fn are_coupons_enabled(db: &dyn SemanticGroup, module_file_id: ModuleFileId) -> bool {
+    if db.target_config().experimental_features.coupons {
+        return true;
+    }
     let owning_crate = module_file_id.0.owning_crate(db.upcast());
     let Some(config) = db.crate_config(owning_crate) else { return false };
     config.settings.experimental_features.coupons
@@ -9,6 +9,7 @@ use cairo_lang_lowering as lowering;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra::ids::ConcreteLibfuncId;
 use cairo_lang_utils::Intern;
+use cairo_lang_utils::ice::with_context;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 
@@ -35,8 +36,13 @@ pub fn priv_function_with_body_sierra_data(
     db: &dyn SierraGenGroup,
     function_id: ConcreteFunctionWithBodyId,
 ) -> SierraFunctionWithBodyData {
-    let function = get_function_code(db, function_id);
-    SierraFunctionWithBodyData { function }
+    with_context(
+        format!(
+            "generating Sierra for function `{}`",
+            function_id.base_semantic_function(db.upcast()).full_path(db.upcast())
+        ),
+        || SierraFunctionWithBodyData { function: get_function_code(db, function_id) },
+    )
 }
 
 /// Query implementation of [SierraGenGroup::function_with_body_sierra].
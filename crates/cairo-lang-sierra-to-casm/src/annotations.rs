@@ -37,6 +37,16 @@ pub enum AnnotationError {
     },
     #[error("#{statement_idx}: {error}")]
     InconsistentEnvironments { statement_idx: StatementIdx, error: EnvironmentError },
+    #[error(
+        "#{statement_idx}: Merged branches from #{expected_source_statement_idx} and \
+         #{actual_source_statement_idx} are misaligned: {error}"
+    )]
+    MisalignedBranches {
+        statement_idx: StatementIdx,
+        expected_source_statement_idx: StatementIdx,
+        actual_source_statement_idx: StatementIdx,
+        error: EnvironmentError,
+    },
     #[error("#{statement_idx}: Belongs to two different functions.")]
     InconsistentFunctionId { statement_idx: StatementIdx },
     #[error("#{statement_idx}: Invalid convergence.")]
@@ -127,6 +137,8 @@ pub struct StatementAnnotations {
 pub struct ProgramAnnotations {
     /// Optional per statement annotation.
     per_statement_annotations: Vec<Option<StatementAnnotations>>,
+    /// The statement that first propagated its annotations to each statement, if there is one.
+    per_statement_sources: Vec<Option<StatementIdx>>,
     /// The indices of the statements that are the targets of backwards jumps.
     backwards_jump_indices: UnorderedHashSet<StatementIdx>,
 }
@@ -134,6 +146,7 @@ impl ProgramAnnotations {
     fn new(n_statements: usize, backwards_jump_indices: UnorderedHashSet<StatementIdx>) -> Self {
         ProgramAnnotations {
             per_statement_annotations: iter::repeat_with(|| None).take(n_statements).collect(),
+            per_statement_sources: vec![None; n_statements],
             backwards_jump_indices,
         }
     }
@@ -150,7 +163,7 @@ impl ProgramAnnotations {
     ) -> Result<Self, AnnotationError> {
         let mut annotations = ProgramAnnotations::new(n_statements, backwards_jump_indices);
         for func in functions {
-            annotations.set_or_assert(func.entry_point, StatementAnnotations {
+            annotations.set_or_assert(func.entry_point, None, StatementAnnotations {
                 refs: build_function_parameters_refs(func, type_sizes).map_err(|error| {
                     AnnotationError::ReferencesError { statement_idx: func.entry_point, error }
                 })?,
@@ -167,18 +180,23 @@ impl ProgramAnnotations {
         Ok(annotations)
     }
 
-    /// Sets the annotations at 'statement_idx' to 'annotations'
+    /// Sets the annotations at 'statement_idx' to 'annotations', where `source_statement_idx` is
+    /// the statement propagating the annotations, if there is one.
     /// If the annotations for this statement were set previously asserts that the previous
     /// assignment is consistent with the new assignment and verifies that convergence_allowed
     /// is true.
     pub fn set_or_assert(
         &mut self,
         statement_idx: StatementIdx,
+        source_statement_idx: Option<StatementIdx>,
         annotations: StatementAnnotations,
     ) -> Result<(), AnnotationError> {
         let idx = statement_idx.0;
         match self.per_statement_annotations.get(idx).ok_or(AnnotationError::InvalidStatementIdx)? {
-            None => self.per_statement_annotations[idx] = Some(annotations),
+            None => {
+                self.per_statement_annotations[idx] = Some(annotations);
+                self.per_statement_sources[idx] = source_statement_idx;
+            }
             Some(expected_annotations) => {
                 if expected_annotations.function_id != annotations.function_id {
                     return Err(AnnotationError::InconsistentFunctionId { statement_idx });
@@ -187,9 +205,19 @@ impl ProgramAnnotations {
                     &expected_annotations.environment,
                     &annotations.environment,
                 )
-                .map_err(|error| AnnotationError::InconsistentEnvironments {
-                    statement_idx,
-                    error,
+                .map_err(|error| {
+                    match (self.per_statement_sources[idx], source_statement_idx) {
+                        (
+                            Some(expected_source_statement_idx),
+                            Some(actual_source_statement_idx),
+                        ) => AnnotationError::MisalignedBranches {
+                            statement_idx,
+                            expected_source_statement_idx,
+                            actual_source_statement_idx,
+                            error,
+                        },
+                        _ => AnnotationError::InconsistentEnvironments { statement_idx, error },
+                    }
                 })?;
                 self.test_references_consistency(&annotations, expected_annotations).map_err(
                     |error| AnnotationError::InconsistentReferencesAnnotation {
@@ -391,25 +419,29 @@ impl ProgramAnnotations {
             }
         };
 
-        self.set_or_assert(destination_statement_idx, StatementAnnotations {
-            refs,
-            function_id: annotations.function_id,
-            convergence_allowed: !must_set,
-            environment: Environment {
-                ap_tracking,
-                stack_size,
-                frame_state: annotations.environment.frame_state,
-                gas_wallet: annotations
-                    .environment
-                    .gas_wallet
-                    .update(branch_changes.gas_change)
-                    .map_err(|error| AnnotationError::GasWalletError {
-                        source_statement_idx,
-                        destination_statement_idx,
-                        error,
-                    })?,
+        self.set_or_assert(
+            destination_statement_idx,
+            Some(source_statement_idx),
+            StatementAnnotations {
+                refs,
+                function_id: annotations.function_id,
+                convergence_allowed: !must_set,
+                environment: Environment {
+                    ap_tracking,
+                    stack_size,
+                    frame_state: annotations.environment.frame_state,
+                    gas_wallet: annotations
+                        .environment
+                        .gas_wallet
+                        .update(branch_changes.gas_change)
+                        .map_err(|error| AnnotationError::GasWalletError {
+                            source_statement_idx,
+                            destination_statement_idx,
+                            error,
+                        })?,
+                },
             },
-        })
+        )
     }
 
     /// Validates the ap change and return types in a return statement.
@@ -795,6 +795,13 @@ fn compiler_errors(
 
     let metadata = match inputs.get("metadata").map(|x| x.as_str()) {
         Some("gas") => calc_metadata(&program, Default::default()),
+        // In this case, branches are not aligned by the metadata.
+        Some("empty") => Ok(Default::default()),
+        // In this case, the gas usage of the branches is not aligned by the metadata.
+        Some("unaligned_gas") => calc_metadata(&program, Default::default()).map(|mut metadata| {
+            metadata.gas_info.variable_values.clear();
+            metadata
+        }),
         Some("none") | None => {
             // In this case, metadata errors are ignored.
             Ok(calc_metadata_ap_change_only(&program).unwrap_or_default())
@@ -810,14 +817,14 @@ fn compiler_errors(
                 .unwrap_or_else(|_| panic!("Invalid value `{x}` for `max_bytecode_size`"))
         })
         .unwrap_or(usize::MAX);
+    let gas_usage_check = inputs.get("gas_usage_check").is_some_and(|x| x == "true");
 
     let error_str = match metadata {
-        Ok(metadata) => compile(&program, &metadata, SierraToCasmConfig {
-            gas_usage_check: false,
-            max_bytecode_size,
-        })
-        .expect_err("Compilation is expected to fail.")
-        .to_string(),
+        Ok(metadata) => {
+            compile(&program, &metadata, SierraToCasmConfig { gas_usage_check, max_bytecode_size })
+                .expect_err("Compilation is expected to fail.")
+                .to_string()
+        }
         Err(err) => err.to_string(),
     };
     TestRunnerResult::success(OrderedHashMap::from([("error".into(), error_str)]))
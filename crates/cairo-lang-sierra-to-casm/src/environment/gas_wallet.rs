@@ -50,6 +50,34 @@ impl GasWallet {
             Self::Disabled => Ok(Self::Disabled),
         }
     }
+
+    /// Describes how an actual wallet differs from this expected one, per cost token type.
+    pub fn describe_difference(&self, actual: &GasWallet) -> String {
+        let (Self::Value(expected_value), Self::Value(actual_value)) = (self, actual) else {
+            return format!("Expected {self}, got {actual}.");
+        };
+        let token_types = expected_value.keys().chain(
+            actual_value.keys().filter(|token_type| !expected_value.contains_key(*token_type)),
+        );
+        let differences = token_types
+            .filter_map(|token_type| {
+                let expected = expected_value.get(token_type).copied().unwrap_or_default();
+                let actual = actual_value.get(token_type).copied().unwrap_or_default();
+                (expected != actual).then(|| {
+                    format!(
+                        "{}: expected {expected}, got {actual} (difference {:+})",
+                        token_type.name(),
+                        actual - expected
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        if differences.is_empty() {
+            // The wallets only differ in tokens with no cost.
+            return format!("Expected {self}, got {actual}.");
+        }
+        format!("{}.", differences.join(", "))
+    }
 }
 
 impl Display for GasWallet {
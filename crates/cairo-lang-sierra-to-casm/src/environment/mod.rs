@@ -13,10 +13,12 @@ pub mod gas_wallet;
 pub enum EnvironmentError {
     #[error("Inconsistent ap tracking.")]
     InconsistentApTracking,
+    #[error("Inconsistent ap change. Expected {expected}, got {actual}.")]
+    InconsistentApChange { expected: usize, actual: usize },
     #[error("Inconsistent frame state.")]
     InconsistentFrameState,
-    #[error("Inconsistent gas wallet state.")]
-    InconsistentGasWallet,
+    #[error("Inconsistent gas wallet state. {}", expected.describe_difference(actual))]
+    InconsistentGasWallet { expected: Box<GasWallet>, actual: Box<GasWallet> },
     #[error("{0}")]
     InvalidFinalFrameState(FrameStateError),
 }
@@ -67,11 +69,20 @@ pub fn validate_environment_equality(
     b: &Environment,
 ) -> Result<(), EnvironmentError> {
     if a.ap_tracking != b.ap_tracking {
-        Err(EnvironmentError::InconsistentApTracking)
+        Err(match (a.ap_tracking, b.ap_tracking) {
+            (
+                ApTracking::Enabled { ap_change: expected, base: a_base },
+                ApTracking::Enabled { ap_change: actual, base: b_base },
+            ) if a_base == b_base => EnvironmentError::InconsistentApChange { expected, actual },
+            _ => EnvironmentError::InconsistentApTracking,
+        })
     } else if a.frame_state != b.frame_state {
         Err(EnvironmentError::InconsistentFrameState)
     } else if a.gas_wallet != b.gas_wallet {
-        Err(EnvironmentError::InconsistentGasWallet)
+        Err(EnvironmentError::InconsistentGasWallet {
+            expected: Box::new(a.gas_wallet.clone()),
+            actual: Box::new(b.gas_wallet.clone()),
+        })
     } else {
         Ok(())
     }
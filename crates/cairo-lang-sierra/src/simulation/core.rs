@@ -6,8 +6,8 @@ use num_integer::Integer;
 use num_traits::{One, ToPrimitive, Zero};
 use starknet_types_core::felt::{Felt as Felt252, NonZeroFelt as NonZeroFelt252};
 
-use super::LibfuncSimulationError;
 use super::value::CoreValue;
+use super::{GasMode, LibfuncSimulationError};
 use crate::extensions::array::ArrayConcreteLibfunc;
 use crate::extensions::boolean::BoolConcreteLibfunc;
 use crate::extensions::core::CoreConcreteLibfunc;
@@ -46,6 +46,8 @@ macro_rules! take_inputs {
 /// Simulates the run of a single libfunc. Returns the value representations of the outputs, and
 /// the chosen branch given the inputs.
 ///
+/// `gas_mode` determines whether gas withdrawals may fail.
+///
 /// `simulate_function` is a function that simulates running of a user function. It is provided here
 /// for the case where the extensions need to use it.
 pub fn simulate<
@@ -54,6 +56,7 @@ pub fn simulate<
 >(
     libfunc: &CoreConcreteLibfunc,
     inputs: Vec<CoreValue>,
+    gas_mode: GasMode,
     get_statement_gas_info: GetStatementGasInfo,
     simulate_function: SimulateFunction,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
@@ -98,7 +101,7 @@ pub fn simulate<
             let count = get_statement_gas_info()
                 .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
             take_inputs!(let [CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)] = inputs);
-            if gas_counter >= count {
+            if gas_mode == GasMode::Unlimited || gas_counter >= count {
                 // Have enough gas - return reduced counter and jump to success branch.
                 (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter - count)], 0)
             } else {
@@ -114,7 +117,12 @@ pub fn simulate<
         }
         CoreConcreteLibfunc::Gas(GasConcreteLibfunc::GetAvailableGas(_)) => {
             take_inputs!(let [CoreValue::GasBuiltin(gas_counter)] = inputs);
-            (vec![CoreValue::GasBuiltin(gas_counter), CoreValue::Uint128(gas_counter as u128)], 0)
+            let available_gas = match gas_mode {
+                GasMode::Limited => gas_counter as u128,
+                // The counter may be negative, but the available gas is unbounded.
+                GasMode::Unlimited => u128::MAX,
+            };
+            (vec![CoreValue::GasBuiltin(gas_counter), CoreValue::Uint128(available_gas)], 0)
         }
         CoreConcreteLibfunc::Gas(
             GasConcreteLibfunc::BuiltinWithdrawGas(_) | GasConcreteLibfunc::GetBuiltinCosts(_),
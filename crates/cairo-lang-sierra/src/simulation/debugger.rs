@@ -0,0 +1,232 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use super::value::CoreValue;
+use super::{GasMode, LibfuncSimulationError, SimulationContext, SimulationError, StatementResult};
+use crate::extensions::core::{CoreLibfunc, CoreType};
+use crate::ids::{FunctionId, VarId};
+use crate::program::{Program, Statement, StatementIdx};
+use crate::program_registry::ProgramRegistry;
+
+#[cfg(test)]
+#[path = "debugger_test.rs"]
+mod test;
+
+/// A frame of the call stack of a debugged simulation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    /// The function running in this frame.
+    pub function_id: FunctionId,
+    /// The next statement to run in this frame. For frames that are not at the top of the call
+    /// stack, this is the statement of the function call currently running.
+    pub statement_idx: StatementIdx,
+    /// The live variables of the frame.
+    pub variables: OrderedHashMap<VarId, CoreValue>,
+}
+
+/// The reason a debugged simulation stopped advancing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StopReason {
+    /// A single statement was run.
+    Step,
+    /// The next statement to run has a breakpoint.
+    Breakpoint(StatementIdx),
+    /// The simulated function returned with the given outputs.
+    Finished(Vec<CoreValue>),
+}
+
+/// A simulation of a function that may be advanced a statement at a time, and inspected between
+/// steps.
+pub struct Debugger<'a> {
+    program: &'a Program,
+    statement_gas_info: &'a HashMap<StatementIdx, i64>,
+    registry: ProgramRegistry<CoreType, CoreLibfunc>,
+    gas_mode: GasMode,
+    /// The call stack of the simulation, where the last frame is the currently running one.
+    call_stack: Vec<Frame>,
+    /// The statements the simulation should stop before running.
+    breakpoints: HashSet<StatementIdx>,
+    /// The outputs of the simulated function, once it returned.
+    outputs: Option<Vec<CoreValue>>,
+}
+impl<'a> Debugger<'a> {
+    /// Prepares a simulation of a function from the program with the given inputs, stopped before
+    /// its first statement.
+    pub fn new(
+        program: &'a Program,
+        statement_gas_info: &'a HashMap<StatementIdx, i64>,
+        gas_mode: GasMode,
+        function_id: &FunctionId,
+        inputs: Vec<CoreValue>,
+    ) -> Result<Self, SimulationError> {
+        let mut debugger = Self {
+            program,
+            statement_gas_info,
+            registry: ProgramRegistry::new(program)?,
+            gas_mode,
+            call_stack: vec![],
+            breakpoints: HashSet::new(),
+            outputs: None,
+        };
+        let frame = new_frame(&debugger.context(), function_id, inputs)?;
+        debugger.call_stack.push(frame);
+        Ok(debugger)
+    }
+
+    /// Adds a breakpoint on a statement.
+    pub fn add_breakpoint(&mut self, statement_idx: StatementIdx) {
+        self.breakpoints.insert(statement_idx);
+    }
+
+    /// Removes a breakpoint from a statement. Returns whether there was such a breakpoint.
+    pub fn remove_breakpoint(&mut self, statement_idx: StatementIdx) -> bool {
+        self.breakpoints.remove(&statement_idx)
+    }
+
+    /// Adds a breakpoint on the entry point of a function.
+    pub fn add_function_breakpoint(
+        &mut self,
+        function_id: &FunctionId,
+    ) -> Result<(), SimulationError> {
+        let entry_point = self.registry.get_function(function_id)?.entry_point;
+        self.add_breakpoint(entry_point);
+        Ok(())
+    }
+
+    /// Returns the call stack, where the last frame is the currently running one.
+    /// The call stack is empty once the simulated function returned.
+    pub fn call_stack(&self) -> &[Frame] {
+        &self.call_stack
+    }
+
+    /// Returns the next statement to run, if the simulation did not finish.
+    pub fn current_statement(&self) -> Option<StatementIdx> {
+        self.call_stack.last().map(|frame| frame.statement_idx)
+    }
+
+    /// Returns the live variables of the currently running function, if the simulation did not
+    /// finish.
+    pub fn variables(&self) -> Option<&OrderedHashMap<VarId, CoreValue>> {
+        self.call_stack.last().map(|frame| &frame.variables)
+    }
+
+    /// Runs until reaching a statement with a breakpoint, or until the simulated function returns.
+    /// Always runs at least one statement, so that resuming from a breakpoint makes progress.
+    pub fn resume(&mut self) -> Result<StopReason, SimulationError> {
+        loop {
+            match self.step()? {
+                StopReason::Step => {}
+                stop_reason => return Ok(stop_reason),
+            }
+        }
+    }
+
+    /// Runs a single statement.
+    ///
+    /// Returns [StopReason::Breakpoint] if the next statement to run has a breakpoint, and
+    /// [StopReason::Finished] if the simulated function returned. On failure, the simulation is
+    /// left as it was before the step.
+    pub fn step(&mut self) -> Result<StopReason, SimulationError> {
+        if let Some(outputs) = &self.outputs {
+            return Ok(StopReason::Finished(outputs.clone()));
+        }
+        let context = self.context();
+        let depth = self.call_stack.len() - 1;
+        let frame = &self.call_stack[depth];
+        let result = context
+            .run_statement(&frame.function_id, frame.statement_idx, frame.variables.clone(), false)
+            .map_err(|error| {
+                wrap_error(&self.call_stack[..depth], frame.function_id.clone(), error)
+            })?;
+        match result {
+            StatementResult::Continue(next_statement_id, state) => {
+                let frame = &mut self.call_stack[depth];
+                frame.statement_idx = next_statement_id;
+                frame.variables = state;
+            }
+            StatementResult::Call { function_id, inputs, state } => {
+                // The caller frame stays on the call statement until the callee returns.
+                let callee = new_frame(&context, &function_id, inputs)
+                    .map_err(|error| wrap_error(&self.call_stack, function_id, error))?;
+                self.call_stack[depth].variables = state;
+                self.call_stack.push(callee);
+            }
+            StatementResult::Return(outputs) => {
+                let Some(caller_depth) = depth.checked_sub(1) else {
+                    self.call_stack.pop();
+                    self.outputs = Some(outputs.clone());
+                    return Ok(StopReason::Finished(outputs));
+                };
+                let caller = &self.call_stack[caller_depth];
+                let Some(Statement::Invocation(invocation)) =
+                    self.program.get_statement(&caller.statement_idx)
+                else {
+                    unreachable!("A caller frame must be stopped on a function call.");
+                };
+                let (next_statement_id, state) = context
+                    .put_branch_results(
+                        caller.statement_idx,
+                        invocation,
+                        0,
+                        caller.variables.clone(),
+                        outputs,
+                    )
+                    .map_err(|error| {
+                        wrap_error(
+                            &self.call_stack[..caller_depth],
+                            caller.function_id.clone(),
+                            error,
+                        )
+                    })?;
+                self.call_stack.pop();
+                let caller = &mut self.call_stack[caller_depth];
+                caller.statement_idx = next_statement_id;
+                caller.variables = state;
+            }
+        }
+        let next_statement_id = self.current_statement().expect("The simulation did not finish.");
+        Ok(if self.breakpoints.contains(&next_statement_id) {
+            StopReason::Breakpoint(next_statement_id)
+        } else {
+            StopReason::Step
+        })
+    }
+
+    /// Returns a context for running statements of the simulated program.
+    fn context(&self) -> SimulationContext<'_> {
+        SimulationContext {
+            program: self.program,
+            statement_gas_info: self.statement_gas_info,
+            registry: &self.registry,
+            gas_mode: self.gas_mode,
+        }
+    }
+}
+
+/// Returns a new frame for running a function with the given inputs.
+fn new_frame(
+    context: &SimulationContext<'_>,
+    function_id: &FunctionId,
+    inputs: Vec<CoreValue>,
+) -> Result<Frame, SimulationError> {
+    let (statement_idx, variables) = context.function_entry(function_id, inputs)?;
+    Ok(Frame { function_id: function_id.clone(), statement_idx, variables })
+}
+
+/// Wraps an error of running a function called from the given frames, the same way errors of
+/// function calls are wrapped when simulating a function in a single run.
+fn wrap_error(
+    callers: &[Frame],
+    mut function_id: FunctionId,
+    mut error: SimulationError,
+) -> SimulationError {
+    for caller in callers.iter().rev() {
+        error = SimulationError::LibfuncSimulationError(
+            LibfuncSimulationError::FunctionSimulationError(function_id, Box::new(error)),
+            caller.statement_idx,
+        );
+        function_id = caller.function_id.clone();
+    }
+    error
+}
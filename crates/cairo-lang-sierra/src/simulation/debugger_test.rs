@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use indoc::indoc;
+
+use super::{Debugger, StopReason};
+use crate::ProgramParser;
+use crate::program::{Program, StatementIdx};
+use crate::simulation::value::CoreValue;
+use crate::simulation::{GasMode, run};
+
+/// Returns a program where `Quadruple` calls `Double` twice.
+fn quadruple_program() -> Program {
+    ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc felt252_add = felt252_add;
+            libfunc felt252_dup = dup<felt252>;
+            libfunc call_double = function_call<user@Double>;
+
+            felt252_dup(x) -> (x, y);
+            felt252_add(x, y) -> (x);
+            return(x);
+
+            call_double(x) -> (x);
+            call_double(x) -> (x);
+            return(x);
+
+            Double@0(x: felt252) -> (felt252);
+            Quadruple@3(x: felt252) -> (felt252);
+        "})
+        .unwrap()
+}
+
+#[test]
+fn test_step() {
+    let program = quadruple_program();
+    let gas_info = HashMap::new();
+    let mut debugger = Debugger::new(
+        &program,
+        &gas_info,
+        GasMode::Limited,
+        &"Quadruple".into(),
+        vec![CoreValue::Felt252(3.into())],
+    )
+    .unwrap();
+    let mut statements = vec![];
+    loop {
+        statements.push((
+            debugger.current_statement().unwrap().0,
+            debugger.call_stack().len(),
+            debugger.variables().unwrap().values().cloned().collect::<Vec<_>>(),
+        ));
+        match debugger.step().unwrap() {
+            StopReason::Step => {}
+            stop_reason => {
+                assert_eq!(stop_reason, StopReason::Finished(vec![CoreValue::Felt252(12.into())]));
+                break;
+            }
+        }
+    }
+    assert_eq!(
+        statements,
+        vec![
+            (3, 1, vec![CoreValue::Felt252(3.into())]),
+            (0, 2, vec![CoreValue::Felt252(3.into())]),
+            (1, 2, vec![CoreValue::Felt252(3.into()), CoreValue::Felt252(3.into())]),
+            (2, 2, vec![CoreValue::Felt252(6.into())]),
+            (4, 1, vec![CoreValue::Felt252(6.into())]),
+            (0, 2, vec![CoreValue::Felt252(6.into())]),
+            (1, 2, vec![CoreValue::Felt252(6.into()), CoreValue::Felt252(6.into())]),
+            (2, 2, vec![CoreValue::Felt252(12.into())]),
+            (5, 1, vec![CoreValue::Felt252(12.into())]),
+        ]
+    );
+    assert!(debugger.call_stack().is_empty());
+    assert_eq!(debugger.step(), Ok(StopReason::Finished(vec![CoreValue::Felt252(12.into())])));
+}
+
+#[test]
+fn test_breakpoints() {
+    let program = quadruple_program();
+    let gas_info = HashMap::new();
+    let mut debugger = Debugger::new(
+        &program,
+        &gas_info,
+        GasMode::Limited,
+        &"Quadruple".into(),
+        vec![CoreValue::Felt252(3.into())],
+    )
+    .unwrap();
+    debugger.add_function_breakpoint(&"Double".into()).unwrap();
+    debugger.add_breakpoint(StatementIdx(5));
+
+    assert_eq!(debugger.resume(), Ok(StopReason::Breakpoint(StatementIdx(0))));
+    assert_eq!(
+        debugger.call_stack().iter().map(|frame| frame.function_id.clone()).collect::<Vec<_>>(),
+        vec!["Quadruple".into(), "Double".into()]
+    );
+    assert_eq!(debugger.resume(), Ok(StopReason::Breakpoint(StatementIdx(0))));
+    assert_eq!(
+        debugger.variables().unwrap().values().cloned().collect::<Vec<_>>(),
+        vec![CoreValue::Felt252(6.into())]
+    );
+    assert!(debugger.remove_breakpoint(StatementIdx(0)));
+    assert_eq!(debugger.resume(), Ok(StopReason::Breakpoint(StatementIdx(5))));
+    assert_eq!(debugger.resume(), Ok(StopReason::Finished(vec![CoreValue::Felt252(12.into())])));
+}
+
+#[test]
+fn test_failing_step() {
+    // `Double` adds a variable that was never defined.
+    let program = ProgramParser::new()
+        .parse(indoc! {"
+            type felt252 = felt252;
+
+            libfunc felt252_add = felt252_add;
+            libfunc felt252_dup = dup<felt252>;
+            libfunc call_double = function_call<user@Double>;
+
+            felt252_dup(x) -> (x, y);
+            felt252_add(x, z) -> (x);
+            return(x);
+
+            call_double(x) -> (x);
+            return(x);
+
+            Double@0(x: felt252) -> (felt252);
+            Twice@3(x: felt252) -> (felt252);
+        "})
+        .unwrap();
+    let gas_info = HashMap::new();
+    // The error of running the whole function at once.
+    let expected_error = || {
+        run(&program, &gas_info, &"Twice".into(), vec![CoreValue::Felt252(3.into())]).unwrap_err()
+    };
+    let mut debugger = Debugger::new(
+        &program,
+        &gas_info,
+        GasMode::Limited,
+        &"Twice".into(),
+        vec![CoreValue::Felt252(3.into())],
+    )
+    .unwrap();
+    assert_eq!(debugger.resume(), Err(expected_error()));
+    let call_stack = debugger.call_stack().to_vec();
+    assert_eq!(debugger.current_statement(), Some(StatementIdx(1)));
+    // The failing statement did not change the simulation, so it fails again the same way.
+    assert_eq!(debugger.step(), Err(expected_error()));
+    assert_eq!(debugger.call_stack(), call_stack);
+}
@@ -6,12 +6,14 @@ use thiserror::Error;
 
 use self::value::CoreValue;
 use crate::edit_state::{EditStateError, put_results, take_args};
-use crate::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
+use crate::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType, CoreTypeConcrete};
+use crate::extensions::function_call::SignatureAndFunctionConcreteLibfunc;
 use crate::ids::{FunctionId, VarId};
-use crate::program::{Program, Statement, StatementIdx};
+use crate::program::{Invocation, Program, Statement, StatementIdx};
 use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
 
 pub mod core;
+pub mod debugger;
 #[cfg(test)]
 mod test;
 pub mod value;
@@ -46,6 +48,18 @@ pub enum SimulationError {
     FunctionDidNotConsumeAllArgs(FunctionId, StatementIdx),
 }
 
+/// The way gas is handled during a simulation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GasMode {
+    /// Gas is withdrawn from the provided `GasBuiltin` values, and withdrawals fail when there is
+    /// not enough gas.
+    #[default]
+    Limited,
+    /// Gas withdrawals never fail, and the gas counter may become negative.
+    /// Statements missing from the gas info are considered to cost no gas.
+    Unlimited,
+}
+
 /// Runs a function from the program with the given inputs.
 pub fn run(
     program: &Program,
@@ -57,15 +71,91 @@ pub fn run(
         program,
         statement_gas_info,
         registry: &ProgramRegistry::new(program)?,
+        gas_mode: GasMode::Limited,
     };
     context.simulate_function(function_id, inputs)
 }
 
+/// Runs a function from the program with the given inputs, with unlimited gas.
+///
+/// The `GasBuiltin` params of the function should not be part of `inputs`, and are not part of the
+/// returned outputs, nor of the expected argument count reported on a mismatch. Returns the outputs
+/// of the function, and the total gas consumed by the run.
+pub fn run_with_unlimited_gas(
+    program: &Program,
+    statement_gas_info: &HashMap<StatementIdx, i64>,
+    function_id: &FunctionId,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, i64), SimulationError> {
+    let registry = ProgramRegistry::new(program)?;
+    let context = SimulationContext {
+        program,
+        statement_gas_info,
+        registry: &registry,
+        gas_mode: GasMode::Unlimited,
+    };
+    let func = registry.get_function(function_id)?;
+    let is_gas_builtin = |ty| -> Result<bool, SimulationError> {
+        Ok(matches!(registry.get_type(ty)?, CoreTypeConcrete::GasBuiltin(_)))
+    };
+    let mut non_gas_param_count = 0;
+    for ty in &func.signature.param_types {
+        if !is_gas_builtin(ty)? {
+            non_gas_param_count += 1;
+        }
+    }
+    if non_gas_param_count != inputs.len() {
+        return Err(SimulationError::FunctionArgumentCountMismatch {
+            function_id: function_id.clone(),
+            expected: non_gas_param_count,
+            actual: inputs.len(),
+        });
+    }
+    let mut inputs = inputs.into_iter();
+    let mut full_inputs = vec![];
+    for ty in &func.signature.param_types {
+        full_inputs.push(if is_gas_builtin(ty)? {
+            CoreValue::GasBuiltin(0)
+        } else {
+            inputs.next().expect("The number of inputs was checked.")
+        });
+    }
+    let full_outputs = context.simulate_function(function_id, full_inputs)?;
+    let mut outputs = vec![];
+    let mut consumed_gas = 0;
+    for (ty, output) in izip!(&func.signature.ret_types, full_outputs) {
+        match output {
+            CoreValue::GasBuiltin(gas_counter) if is_gas_builtin(ty)? => {
+                consumed_gas -= gas_counter;
+            }
+            output => outputs.push(output),
+        }
+    }
+    Ok((outputs, consumed_gas))
+}
+
+/// The result of running a single statement.
+enum StatementResult {
+    /// The function continues from the given statement, with the given state.
+    Continue(StatementIdx, OrderedHashMap<VarId, CoreValue>),
+    /// The function returned the given outputs.
+    Return(Vec<CoreValue>),
+    /// The statement calls a function with the given inputs. Once the call returns, the function
+    /// continues with the given state, and the outputs of the call put according to the first
+    /// branch of the statement.
+    Call {
+        function_id: FunctionId,
+        inputs: Vec<CoreValue>,
+        state: OrderedHashMap<VarId, CoreValue>,
+    },
+}
+
 /// Helper class for running the simulation.
 struct SimulationContext<'a> {
     pub program: &'a Program,
     pub statement_gas_info: &'a HashMap<StatementIdx, i64>,
     pub registry: &'a ProgramRegistry<CoreType, CoreLibfunc>,
+    pub gas_mode: GasMode,
 }
 impl SimulationContext<'_> {
     /// Simulates the run of a function, even recursively.
@@ -74,8 +164,26 @@ impl SimulationContext<'_> {
         function_id: &FunctionId,
         inputs: Vec<CoreValue>,
     ) -> Result<Vec<CoreValue>, SimulationError> {
+        let (mut current_statement_id, mut state) = self.function_entry(function_id, inputs)?;
+        loop {
+            match self.run_statement(function_id, current_statement_id, state, true)? {
+                StatementResult::Continue(next_statement_id, next_state) => {
+                    current_statement_id = next_statement_id;
+                    state = next_state;
+                }
+                StatementResult::Return(outputs) => return Ok(outputs),
+                StatementResult::Call { .. } => unreachable!("Calls are simulated inline."),
+            }
+        }
+    }
+
+    /// Returns the entry point of a function, and the state on entry given its inputs.
+    fn function_entry(
+        &self,
+        function_id: &FunctionId,
+        inputs: Vec<CoreValue>,
+    ) -> Result<(StatementIdx, OrderedHashMap<VarId, CoreValue>), SimulationError> {
         let func = self.registry.get_function(function_id)?;
-        let mut current_statement_id = func.entry_point;
         if func.params.len() != inputs.len() {
             return Err(SimulationError::FunctionArgumentCountMismatch {
                 function_id: func.id.clone(),
@@ -83,50 +191,97 @@ impl SimulationContext<'_> {
                 actual: inputs.len(),
             });
         }
-        let mut state = OrderedHashMap::<VarId, CoreValue>::from_iter(
+        let state = OrderedHashMap::from_iter(
             izip!(func.params.iter(), inputs).map(|(param, input)| (param.id.clone(), input)),
         );
-        loop {
-            let statement = self
-                .program
-                .get_statement(&current_statement_id)
-                .ok_or(SimulationError::StatementOutOfBounds(current_statement_id))?;
-            match statement {
-                Statement::Return(ids) => {
-                    let (remaining, outputs) = take_args(state, ids.iter()).map_err(|error| {
+        Ok((func.entry_point, state))
+    }
+
+    /// Runs a single statement of a function, given the state before it.
+    ///
+    /// If `inline_calls` is true, function calls are simulated as a single statement. Otherwise,
+    /// [StatementResult::Call] is returned for them.
+    fn run_statement(
+        &self,
+        function_id: &FunctionId,
+        current_statement_id: StatementIdx,
+        state: OrderedHashMap<VarId, CoreValue>,
+        inline_calls: bool,
+    ) -> Result<StatementResult, SimulationError> {
+        let statement = self
+            .program
+            .get_statement(&current_statement_id)
+            .ok_or(SimulationError::StatementOutOfBounds(current_statement_id))?;
+        match statement {
+            Statement::Return(ids) => {
+                let (remaining, outputs) = take_args(state, ids.iter()).map_err(|error| {
+                    SimulationError::EditStateError(error, current_statement_id)
+                })?;
+                if remaining.is_empty() {
+                    Ok(StatementResult::Return(outputs))
+                } else {
+                    Err(SimulationError::FunctionDidNotConsumeAllArgs(
+                        function_id.clone(),
+                        current_statement_id,
+                    ))
+                }
+            }
+            Statement::Invocation(invocation) => {
+                let (remaining, inputs) =
+                    take_args(state, invocation.args.iter()).map_err(|error| {
                         SimulationError::EditStateError(error, current_statement_id)
                     })?;
-                    return if remaining.is_empty() {
-                        Ok(outputs)
-                    } else {
-                        Err(SimulationError::FunctionDidNotConsumeAllArgs(
-                            func.id.clone(),
-                            current_statement_id,
-                        ))
-                    };
-                }
-                Statement::Invocation(invocation) => {
-                    let (remaining, inputs) =
-                        take_args(state, invocation.args.iter()).map_err(|error| {
-                            SimulationError::EditStateError(error, current_statement_id)
-                        })?;
-                    let libfunc = self.registry.get_libfunc(&invocation.libfunc_id)?;
-                    let (outputs, chosen_branch) = self.simulate_libfunc(
-                        &current_statement_id,
-                        libfunc,
-                        inputs,
-                        current_statement_id,
-                    )?;
-                    let branch_info = &invocation.branches[chosen_branch];
-                    state = put_results(remaining, izip!(branch_info.results.iter(), outputs))
-                        .map_err(|error| {
-                            SimulationError::EditStateError(error, current_statement_id)
-                        })?;
-                    current_statement_id = current_statement_id.next(&branch_info.target);
+                let libfunc = self.registry.get_libfunc(&invocation.libfunc_id)?;
+                if !inline_calls {
+                    if let CoreConcreteLibfunc::FunctionCall(
+                        SignatureAndFunctionConcreteLibfunc { function, .. },
+                    )
+                    | CoreConcreteLibfunc::CouponCall(SignatureAndFunctionConcreteLibfunc {
+                        function,
+                        ..
+                    }) = libfunc
+                    {
+                        return Ok(StatementResult::Call {
+                            function_id: function.id.clone(),
+                            inputs,
+                            state: remaining,
+                        });
+                    }
                 }
+                let (outputs, chosen_branch) = self.simulate_libfunc(
+                    &current_statement_id,
+                    libfunc,
+                    inputs,
+                    current_statement_id,
+                )?;
+                let (next_statement_id, next_state) = self.put_branch_results(
+                    current_statement_id,
+                    invocation,
+                    chosen_branch,
+                    remaining,
+                    outputs,
+                )?;
+                Ok(StatementResult::Continue(next_statement_id, next_state))
             }
         }
     }
+
+    /// Puts the outputs of an invocation into the state, according to the chosen branch. Returns
+    /// the next statement to run, and the state before it.
+    fn put_branch_results(
+        &self,
+        current_statement_id: StatementIdx,
+        invocation: &Invocation,
+        chosen_branch: usize,
+        state: OrderedHashMap<VarId, CoreValue>,
+        outputs: Vec<CoreValue>,
+    ) -> Result<(StatementIdx, OrderedHashMap<VarId, CoreValue>), SimulationError> {
+        let branch_info = &invocation.branches[chosen_branch];
+        let state = put_results(state, izip!(branch_info.results.iter(), outputs))
+            .map_err(|error| SimulationError::EditStateError(error, current_statement_id))?;
+        Ok((current_statement_id.next(&branch_info.target), state))
+    }
+
     /// Simulates the run of libfuncs. Returns the memory representations of the outputs given the
     /// inputs.
     fn simulate_libfunc(
@@ -139,7 +294,11 @@ impl SimulationContext<'_> {
         core::simulate(
             libfunc,
             inputs,
-            || self.statement_gas_info.get(idx).copied(),
+            self.gas_mode,
+            || match self.gas_mode {
+                GasMode::Limited => self.statement_gas_info.get(idx).copied(),
+                GasMode::Unlimited => Some(self.statement_gas_info.get(idx).copied().unwrap_or(0)),
+            },
             |function_id, inputs| {
                 self.simulate_function(function_id, inputs).map_err(|error| {
                     LibfuncSimulationError::FunctionSimulationError(
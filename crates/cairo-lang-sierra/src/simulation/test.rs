@@ -8,7 +8,7 @@ use super::LibfuncSimulationError::{
 use super::value::CoreValue::{
     self, Array, GasBuiltin, RangeCheck, Uint32, Uint128, Uninitialized,
 };
-use super::{SimulationError, core};
+use super::{GasMode, SimulationError, core};
 use crate::extensions::GenericLibfunc;
 use crate::extensions::core::CoreLibfunc;
 use crate::extensions::lib_func::{
@@ -124,6 +124,7 @@ fn simulate(
             .specialize(&MockSpecializationContext::new(), &generic_args)
             .unwrap(),
         inputs,
+        GasMode::Limited,
         || Some(4),
         |id, inputs| {
             if id == &"drop_all_inputs".into() {
@@ -241,3 +242,37 @@ fn simulate_error(
 ) -> LibfuncSimulationError {
     simulate(id, generic_args, inputs).err().unwrap()
 }
+
+#[test]
+fn simulate_withdraw_gas_with_unlimited_gas() {
+    assert_eq!(
+        core::simulate(
+            &CoreLibfunc::by_id(&"withdraw_gas".into())
+                .unwrap()
+                .specialize(&MockSpecializationContext::new(), &[])
+                .unwrap(),
+            vec![RangeCheck, GasBuiltin(2)],
+            GasMode::Unlimited,
+            || Some(4),
+            |_, _| unreachable!(),
+        ),
+        Ok((vec![RangeCheck, GasBuiltin(-2)], 0))
+    );
+}
+
+#[test]
+fn simulate_get_available_gas_with_unlimited_gas() {
+    assert_eq!(
+        core::simulate(
+            &CoreLibfunc::by_id(&"get_available_gas".into())
+                .unwrap()
+                .specialize(&MockSpecializationContext::new(), &[])
+                .unwrap(),
+            vec![GasBuiltin(-2)],
+            GasMode::Unlimited,
+            || None,
+            |_, _| unreachable!(),
+        ),
+        Ok((vec![GasBuiltin(-2), Uint128(u128::MAX)], 0))
+    );
+}
@@ -29,6 +29,22 @@ fn create_registry(name: &str) {
     ProgramRegistry::<CoreType, CoreLibfunc>::new(&get_example_program(name)).unwrap();
 }
 
+/// Returns the gas info of the statements of the `fib_jumps` example.
+fn fib_jumps_gas_info() -> HashMap<StatementIdx, i64> {
+    HashMap::from([
+        (StatementIdx(28), 11),
+        (StatementIdx(29), 0),
+        (StatementIdx(40), 0),
+        (StatementIdx(45), 0),
+        (StatementIdx(19), 5),
+        (StatementIdx(22), 0),
+        (StatementIdx(26), 0),
+        (StatementIdx(2), 14),
+        (StatementIdx(4), 0),
+        (StatementIdx(9), 0),
+    ])
+}
+
 #[test_case((1000, 0), (1000, 1); "0 => 1")]
 #[test_case((1000, 1), (989, 1); "1 => 1")]
 #[test_case((1000, 2), (978, 2); "2 => 2")]
@@ -43,18 +59,7 @@ fn simulate_fib_jumps((gb, n): (i64, i128), (new_gb, fib): (i64, i128)) {
     assert_eq!(
         simulation::run(
             &get_example_program("fib_jumps"),
-            &HashMap::from([
-                (StatementIdx(28), 11),
-                (StatementIdx(29), 0),
-                (StatementIdx(40), 0),
-                (StatementIdx(45), 0),
-                (StatementIdx(19), 5),
-                (StatementIdx(22), 0),
-                (StatementIdx(26), 0),
-                (StatementIdx(2), 14),
-                (StatementIdx(4), 0),
-                (StatementIdx(9), 0),
-            ]),
+            &fib_jumps_gas_info(),
             &"Fibonacci".into(),
             vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gb), CoreValue::Felt252(n.into())]
         ),
@@ -92,3 +97,50 @@ fn simulate_fib_no_gas(n: i128, fib: i128) {
         Ok(vec![CoreValue::Felt252(fib.into())])
     );
 }
+
+#[test_case(0, (1, 0); "0 => 1")]
+#[test_case(1, (1, 11); "1 => 1")]
+#[test_case(8, (34, 88); "8 => 34")]
+#[test_case(80, (37889062373143906, 880); "80 => 37889062373143906")]
+fn simulate_fib_jumps_with_unlimited_gas(n: i128, (fib, consumed_gas): (i128, i64)) {
+    assert_eq!(
+        simulation::run_with_unlimited_gas(
+            &get_example_program("fib_jumps"),
+            &fib_jumps_gas_info(),
+            &"Fibonacci".into(),
+            vec![CoreValue::RangeCheck, CoreValue::Felt252(n.into())]
+        ),
+        Ok((vec![CoreValue::RangeCheck, CoreValue::Felt252(fib.into())], consumed_gas))
+    );
+}
+
+#[test]
+fn simulate_fib_jumps_with_unlimited_gas_and_missing_inputs() {
+    assert_eq!(
+        simulation::run_with_unlimited_gas(
+            &get_example_program("fib_jumps"),
+            &fib_jumps_gas_info(),
+            &"Fibonacci".into(),
+            vec![CoreValue::RangeCheck]
+        ),
+        Err(simulation::SimulationError::FunctionArgumentCountMismatch {
+            function_id: "Fibonacci".into(),
+            expected: 2,
+            actual: 1
+        })
+    );
+}
+
+#[test_case(0, 1; "0 => 1")]
+#[test_case(8, 34; "8 => 34")]
+fn simulate_fib_jumps_without_gas_info(n: i128, fib: i128) {
+    assert_eq!(
+        simulation::run_with_unlimited_gas(
+            &get_example_program("fib_jumps"),
+            &HashMap::new(),
+            &"Fibonacci".into(),
+            vec![CoreValue::RangeCheck, CoreValue::Felt252(n.into())]
+        ),
+        Ok((vec![CoreValue::RangeCheck, CoreValue::Felt252(fib.into())], 0))
+    );
+}
@@ -0,0 +1,102 @@
+//! Reporting of internal compiler errors (ICEs).
+//!
+//! Code may describe the work it is doing using [with_context]. Once [install_ice_hook] is called,
+//! any panic is reported as an internal compiler error, along with the descriptions of the work in
+//! progress in the panicking thread. Only the compilation phases that enter a context are
+//! described - currently parsing a file, lowering a function and generating Sierra for a function -
+//! so the report lists the nested phases in progress, not the full query stack.
+
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::panic::Location;
+use std::path::PathBuf;
+
+#[cfg(test)]
+#[path = "ice_test.rs"]
+mod test;
+
+thread_local! {
+    /// The descriptions of the work in progress in the current thread, innermost last.
+    static CONTEXT_STACK: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the contexts pushed by a [with_context] call when dropped, including when unwinding.
+struct ContextGuard {
+    /// The depth of the context stack before entering the context.
+    depth: usize,
+}
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXT_STACK.with(|stack| stack.borrow_mut().truncate(self.depth));
+    }
+}
+
+/// Runs `f` in a context describing the work it does, e.g. "lowering function `foo::bar`".
+/// The description is included in the report of any internal compiler error occurring in `f`.
+///
+/// The description is computed by the caller, so that reporting an error does not run any
+/// compilation code, e.g. database queries, from the panic hook.
+pub fn with_context<T>(description: String, f: impl FnOnce() -> T) -> T {
+    let _guard = CONTEXT_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let depth = stack.len();
+        stack.push(description);
+        ContextGuard { depth }
+    });
+    f()
+}
+
+/// Returns the descriptions of the contexts entered in the current thread, innermost last.
+pub fn current_context() -> Vec<String> {
+    CONTEXT_STACK.with(|stack| stack.borrow().clone())
+}
+
+/// Installs a panic hook reporting panics as internal compiler errors, including the current
+/// context. If `report_path` is provided, a full report, including a backtrace, is also written to
+/// it.
+pub fn install_ice_hook(report_path: Option<PathBuf>) {
+    std::panic::set_hook(Box::new(move |info| {
+        let message = if let Some(message) = info.payload().downcast_ref::<&str>() {
+            message
+        } else if let Some(message) = info.payload().downcast_ref::<String>() {
+            message.as_str()
+        } else {
+            "Unknown panic payload."
+        };
+        let location = info.location();
+        let context = current_context();
+        eprintln!("{}", format_report(message, location, &context, None));
+        let Some(report_path) = &report_path else {
+            return;
+        };
+        let backtrace = Backtrace::force_capture();
+        let report = format_report(message, location, &context, Some(&backtrace));
+        match std::fs::write(report_path, report) {
+            Ok(()) => eprintln!("note: the report was written to `{}`.", report_path.display()),
+            Err(err) => {
+                eprintln!("note: failed writing the report to `{}`: {err}", report_path.display())
+            }
+        }
+    }));
+}
+
+/// Formats the report of an internal compiler error.
+fn format_report(
+    message: &str,
+    location: Option<&Location<'_>>,
+    context: &[String],
+    backtrace: Option<&Backtrace>,
+) -> String {
+    let mut report = format!("error: internal compiler error: {message}");
+    if let Some(location) = location {
+        write!(report, "\n --> {location}").unwrap();
+    }
+    for description in context.iter().rev() {
+        write!(report, "\nnote: while {description}").unwrap();
+    }
+    if let Some(backtrace) = backtrace {
+        write!(report, "\n\nbacktrace:\n{backtrace}").unwrap();
+    }
+    report
+}
@@ -0,0 +1,38 @@
+use std::panic::Location;
+
+use super::{current_context, format_report, with_context};
+
+#[test]
+fn test_context() {
+    assert!(current_context().is_empty());
+    let crate_name = "a".to_string();
+    with_context(format!("compiling crate `{crate_name}`"), || {
+        with_context(format!("lowering function `{crate_name}::b`"), || {
+            assert_eq!(current_context(), vec!["compiling crate `a`", "lowering function `a::b`"]);
+        });
+        assert_eq!(current_context(), vec!["compiling crate `a`"]);
+    });
+    assert!(current_context().is_empty());
+}
+
+#[test]
+fn test_context_unwinding() {
+    let result = std::panic::catch_unwind(|| {
+        with_context("lowering function `a::b`".into(), || panic!("Unexpected."))
+    });
+    assert!(result.is_err());
+    assert!(current_context().is_empty());
+}
+
+#[test]
+fn test_format_report() {
+    let location = Location::caller();
+    let context = vec!["compiling crate `a`".to_string(), "lowering function `a::b`".to_string()];
+    assert_eq!(
+        format_report("Unexpected.", Some(location), &context, None),
+        format!(
+            "error: internal compiler error: Unexpected.\n --> {location}\nnote: while lowering \
+             function `a::b`\nnote: while compiling crate `a`"
+        )
+    );
+}
@@ -16,6 +16,8 @@ pub mod collection_arithmetics;
 pub mod extract_matches;
 #[cfg(feature = "std")]
 pub mod graph_algos;
+#[cfg(feature = "std")]
+pub mod ice;
 pub mod iterators;
 #[cfg(feature = "env_logger")]
 pub mod logging;
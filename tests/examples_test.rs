@@ -324,6 +324,31 @@ fn run_function_auto_gas_test(
     );
 }
 
+#[rstest]
+fn run_function_with_gas_costs_test(example_dir_data: &ExampleDirData) {
+    let pedersen_cost = 2 * token_gas_cost(CostTokenType::Pedersen);
+    let runner = SierraCasmRunner::new(
+        checked_compile_to_sierra("hash_chain_gas", example_dir_data, false),
+        Some(Default::default()),
+        Default::default(),
+        None,
+    )
+    .expect("Failed setting up runner.")
+    .with_gas_costs([(CostTokenType::Pedersen, pedersen_cost)].into_iter().collect());
+    let result = runner
+        .run_function_with_starknet_context(
+            runner.find_function("").expect("Failed finding the function."),
+            &[Arg::Value(Felt252::from(3))],
+            Some(100000),
+            Default::default(),
+        )
+        .expect("Failed running the function.");
+    assert_eq!(
+        Felt252::from(100000) - result.gas_counter.unwrap(),
+        Felt252::from(9880 + 3 * pedersen_cost)
+    );
+}
+
 #[rstest]
 #[case::size_2(2, 1)]
 #[case::size_3(3, 2)]